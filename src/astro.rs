@@ -0,0 +1,360 @@
+//! positional astronomy after Jean Meeus, "Astronomical Algorithms", 2nd edition
+
+use std::f64::consts::PI;
+
+use crate::Datetime;
+
+/// julian ephemeris day of J2000.0
+pub(crate) const J2000: f64 = 2451545.0;
+
+/// truncated VSOP87 series of the earth's heliocentric longitude, (A, B, C) of A cos(B + C τ)
+const L0: [(f64, f64, f64); 64] = [
+    (175347046.0, 0.0, 0.0),
+    (3341656.0, 4.6692568, 6283.07585),
+    (34894.0, 4.6261, 12566.1517),
+    (3497.0, 2.7441, 5753.3849),
+    (3418.0, 2.8289, 3.5231),
+    (3136.0, 3.6277, 77713.7715),
+    (2676.0, 4.4181, 7860.4194),
+    (2343.0, 6.1352, 3930.2097),
+    (1324.0, 0.7425, 11506.7698),
+    (1273.0, 2.0371, 529.691),
+    (1199.0, 1.1096, 1577.3435),
+    (990.0, 5.233, 5884.927),
+    (902.0, 2.045, 26.298),
+    (857.0, 3.508, 398.149),
+    (780.0, 1.179, 5223.694),
+    (753.0, 2.533, 5507.553),
+    (505.0, 4.583, 18849.228),
+    (492.0, 4.205, 775.523),
+    (357.0, 2.92, 0.067),
+    (317.0, 5.849, 11790.629),
+    (284.0, 1.899, 796.298),
+    (271.0, 0.315, 10977.079),
+    (243.0, 0.345, 5486.778),
+    (206.0, 4.806, 2544.314),
+    (205.0, 1.869, 5573.143),
+    (202.0, 2.458, 6069.777),
+    (156.0, 0.833, 213.299),
+    (132.0, 3.411, 2942.463),
+    (126.0, 1.083, 20.775),
+    (115.0, 0.645, 0.98),
+    (103.0, 0.636, 4694.003),
+    (102.0, 0.976, 15720.839),
+    (102.0, 4.267, 7.114),
+    (99.0, 6.21, 2146.17),
+    (98.0, 0.68, 155.42),
+    (86.0, 5.98, 161000.69),
+    (85.0, 1.3, 6275.96),
+    (85.0, 3.67, 71430.7),
+    (80.0, 1.81, 17260.15),
+    (79.0, 3.04, 12036.46),
+    (75.0, 1.76, 5088.63),
+    (74.0, 3.5, 3154.69),
+    (74.0, 4.68, 801.82),
+    (70.0, 0.83, 9437.76),
+    (62.0, 3.98, 8827.39),
+    (61.0, 1.82, 7084.9),
+    (57.0, 2.78, 6286.6),
+    (56.0, 4.39, 14143.5),
+    (56.0, 3.47, 6279.55),
+    (52.0, 0.19, 12139.55),
+    (52.0, 1.33, 1748.02),
+    (51.0, 0.28, 5856.48),
+    (49.0, 0.49, 1194.45),
+    (41.0, 5.37, 8429.24),
+    (41.0, 2.4, 19651.05),
+    (39.0, 6.17, 10447.39),
+    (37.0, 6.04, 10213.29),
+    (37.0, 2.57, 1059.38),
+    (36.0, 1.71, 2352.87),
+    (36.0, 1.78, 6812.77),
+    (33.0, 0.59, 17789.85),
+    (30.0, 0.44, 83996.85),
+    (30.0, 2.74, 1349.87),
+    (25.0, 3.16, 4690.48),
+];
+
+const L1: [(f64, f64, f64); 34] = [
+    (628331966747.0, 0.0, 0.0),
+    (206059.0, 2.678235, 6283.07585),
+    (4303.0, 2.6351, 12566.1517),
+    (425.0, 1.59, 3.523),
+    (119.0, 5.796, 26.298),
+    (109.0, 2.966, 1577.344),
+    (93.0, 2.59, 18849.23),
+    (72.0, 1.14, 529.69),
+    (68.0, 1.87, 398.15),
+    (67.0, 4.41, 5507.55),
+    (59.0, 2.89, 5223.69),
+    (56.0, 2.17, 155.42),
+    (45.0, 0.4, 796.3),
+    (36.0, 0.47, 775.52),
+    (29.0, 2.65, 7.11),
+    (21.0, 5.34, 0.98),
+    (19.0, 1.85, 5486.78),
+    (19.0, 4.97, 213.3),
+    (17.0, 2.99, 6275.96),
+    (16.0, 0.03, 2544.31),
+    (16.0, 1.43, 2146.17),
+    (15.0, 1.21, 10977.08),
+    (12.0, 2.83, 1748.02),
+    (12.0, 3.26, 5088.63),
+    (12.0, 5.27, 1194.45),
+    (12.0, 2.08, 4694.0),
+    (11.0, 0.77, 553.57),
+    (10.0, 1.3, 6286.6),
+    (10.0, 4.24, 1349.87),
+    (9.0, 2.7, 242.73),
+    (9.0, 5.64, 951.72),
+    (8.0, 5.3, 2352.87),
+    (6.0, 2.65, 9437.76),
+    (6.0, 4.67, 4690.48),
+];
+
+const L2: [(f64, f64, f64); 20] = [
+    (52919.0, 0.0, 0.0),
+    (8720.0, 1.0721, 6283.0758),
+    (309.0, 0.867, 12566.152),
+    (27.0, 0.05, 3.52),
+    (16.0, 5.19, 26.3),
+    (16.0, 3.68, 155.42),
+    (10.0, 0.76, 18849.23),
+    (9.0, 2.06, 77713.77),
+    (7.0, 0.83, 775.52),
+    (5.0, 4.66, 1577.34),
+    (4.0, 1.03, 7.11),
+    (4.0, 3.44, 5573.14),
+    (3.0, 5.14, 796.3),
+    (3.0, 6.05, 5507.55),
+    (3.0, 1.19, 242.73),
+    (3.0, 6.12, 529.69),
+    (3.0, 0.31, 398.15),
+    (3.0, 2.28, 553.57),
+    (2.0, 4.38, 5223.69),
+    (2.0, 3.75, 0.98),
+];
+
+const L3: [(f64, f64, f64); 7] = [
+    (289.0, 5.844, 6283.076),
+    (35.0, 0.0, 0.0),
+    (17.0, 5.49, 12566.15),
+    (3.0, 5.2, 155.42),
+    (1.0, 4.72, 3.52),
+    (1.0, 5.3, 18849.23),
+    (1.0, 5.97, 242.73),
+];
+
+const L4: [(f64, f64, f64); 3] = [
+    (114.0, PI, 0.0),
+    (8.0, 4.13, 6283.08),
+    (1.0, 3.84, 12566.15),
+];
+
+const L5: [(f64, f64, f64); 1] = [(1.0, PI, 0.0)];
+
+/// truncated VSOP87 series of the earth's radius vector, enough for the aberration
+const R0: [(f64, f64, f64); 10] = [
+    (100013989.0, 0.0, 0.0),
+    (1670700.0, 3.0984635, 6283.07585),
+    (13956.0, 3.05525, 12566.1517),
+    (3084.0, 5.1985, 77713.7715),
+    (1628.0, 1.1739, 5753.3849),
+    (1576.0, 2.8469, 7860.4194),
+    (925.0, 5.453, 11506.77),
+    (542.0, 4.564, 3930.21),
+    (472.0, 3.661, 5884.927),
+    (346.0, 0.964, 5507.553),
+];
+
+const R1: [(f64, f64, f64); 3] = [
+    (103019.0, 1.10749, 6283.07585),
+    (1721.0, 1.0644, 12566.1517),
+    (702.0, PI, 0.0),
+];
+
+const R2: [(f64, f64, f64); 1] = [(4359.0, 5.7846, 6283.0758)];
+
+fn series(terms: &[(f64, f64, f64)], tau: f64) -> f64 {
+    terms.iter().map(|&(a, b, c)| a * (b + c * tau).cos()).sum()
+}
+
+fn polynomial(series: &[f64], x: f64) -> f64 {
+    series.iter().rev().fold(0.0, |acc, c| acc * x + c)
+}
+
+/// normalize an angle in degrees into 0..360
+pub(crate) fn normalize(degrees: f64) -> f64 {
+    degrees.rem_euclid(360.0)
+}
+
+/// julian centuries since J2000.0
+pub(crate) fn centuries(jde: f64) -> f64 {
+    (jde - J2000) / 36525.0
+}
+
+/// nutation in longitude and in obliquity in degrees, accurate to half an arcsecond
+pub(crate) fn nutation(jde: f64) -> (f64, f64) {
+    let t = centuries(jde);
+    let omega = (125.04452 - 1934.136261 * t).to_radians();
+    let l = (280.4665 + 36000.7698 * t).to_radians();
+    let lm = (218.3165 + 481267.8813 * t).to_radians();
+    let psi = -17.20 * omega.sin() - 1.32 * (2.0 * l).sin() - 0.23 * (2.0 * lm).sin()
+        + 0.21 * (2.0 * omega).sin();
+    let eps = 9.20 * omega.cos() + 0.57 * (2.0 * l).cos() + 0.10 * (2.0 * lm).cos()
+        - 0.09 * (2.0 * omega).cos();
+    (psi / 3600.0, eps / 3600.0)
+}
+
+/// apparent geocentric ecliptic longitude of the sun in degrees, accurate to about an arcsecond
+pub(crate) fn sun_longitude(jde: f64) -> f64 {
+    let tau = (jde - J2000) / 365250.0;
+    let l = polynomial(
+        &[
+            series(&L0, tau),
+            series(&L1, tau),
+            series(&L2, tau),
+            series(&L3, tau),
+            series(&L4, tau),
+            series(&L5, tau),
+        ],
+        tau,
+    ) / 1e8;
+    let r = polynomial(&[series(&R0, tau), series(&R1, tau), series(&R2, tau)], tau) / 1e8;
+
+    // geocentric, corrected to the FK5 system, the latitude is below an arcsecond and ignored
+    let theta = l.to_degrees() + 180.0;
+    let fk5 = -0.09033 / 3600.0;
+    let aberration = -20.4898 / 3600.0 / r;
+    normalize(theta + fk5 + nutation(jde).0 + aberration)
+}
+
+/// apparent right ascension and declination of the sun in degrees
+pub(crate) fn sun_equatorial(jde: f64) -> (f64, f64) {
+    let lambda = sun_longitude(jde).to_radians();
+    let eps = obliquity(jde).to_radians();
+    let ra = (eps.cos() * lambda.sin()).atan2(lambda.cos());
+    let dec = (eps.sin() * lambda.sin()).asin();
+    (normalize(ra.to_degrees()), dec.to_degrees())
+}
+
+/// apparent sidereal time at greenwich in degrees for a julian day in universal time
+pub(crate) fn sidereal_time(jd: f64) -> f64 {
+    let t = centuries(jd);
+    let mean = 280.46061837 + 360.98564736629 * (jd - J2000) + 0.000387933 * t * t
+        - t * t * t / 38710000.0;
+    let (psi, _) = nutation(jd);
+    normalize(mean + psi * obliquity(jd).to_radians().cos())
+}
+
+/// true obliquity of the ecliptic in degrees
+pub(crate) fn obliquity(jde: f64) -> f64 {
+    let t = centuries(jde);
+    let mean = 23.0 + 26.0 / 60.0 + polynomial(&[21.448, -46.815, -0.00059, 0.001813], t) / 3600.0;
+    mean + nutation(jde).1
+}
+
+/// TT - UT in seconds from the polynomials of Espenak and Meeus,
+/// the uncertainty grows from a second today to hours in antiquity
+pub(crate) fn delta_t(year: f64) -> f64 {
+    let u = (year - 1820.0) / 100.0;
+    match year {
+        y if y < -500.0 => -20.0 + 32.0 * u * u,
+        y if y < 500.0 => polynomial(
+            &[
+                10583.6,
+                -1014.41,
+                33.78311,
+                -5.952053,
+                -0.1798452,
+                0.022174192,
+                0.0090316521,
+            ],
+            y / 100.0,
+        ),
+        y if y < 1600.0 => polynomial(
+            &[
+                1574.2,
+                -556.01,
+                71.23472,
+                0.319781,
+                -0.8503463,
+                -0.005050998,
+                0.0083572073,
+            ],
+            (y - 1000.0) / 100.0,
+        ),
+        y if y < 1700.0 => polynomial(&[120.0, -0.9808, -0.01532, 1.0 / 7129.0], y - 1600.0),
+        y if y < 1800.0 => polynomial(
+            &[8.83, 0.1603, -0.0059285, 0.00013336, -1.0 / 1174000.0],
+            y - 1700.0,
+        ),
+        y if y < 1860.0 => polynomial(
+            &[
+                13.72,
+                -0.332447,
+                0.0068612,
+                0.0041116,
+                -0.00037436,
+                0.0000121272,
+                -0.0000001699,
+                0.000000000875,
+            ],
+            y - 1800.0,
+        ),
+        y if y < 1900.0 => polynomial(
+            &[
+                7.62,
+                0.5737,
+                -0.251754,
+                0.01680668,
+                -0.0004473624,
+                1.0 / 233174.0,
+            ],
+            y - 1860.0,
+        ),
+        y if y < 1920.0 => polynomial(
+            &[-2.79, 1.494119, -0.0598939, 0.0061966, -0.000197],
+            y - 1900.0,
+        ),
+        y if y < 1941.0 => polynomial(&[21.20, 0.84493, -0.0761, 0.0020936], y - 1920.0),
+        y if y < 1961.0 => polynomial(&[29.07, 0.407, -1.0 / 233.0, 1.0 / 2547.0], y - 1950.0),
+        y if y < 1986.0 => polynomial(&[45.45, 1.067, -1.0 / 260.0, -1.0 / 718.0], y - 1975.0),
+        y if y < 2005.0 => polynomial(
+            &[
+                63.86,
+                0.3345,
+                -0.060374,
+                0.0017275,
+                0.000651814,
+                0.00002373599,
+            ],
+            y - 2000.0,
+        ),
+        y if y < 2050.0 => polynomial(&[62.92, 0.32217, 0.005589], y - 2000.0),
+        y if y < 2150.0 => -20.0 + 32.0 * u * u - 0.5628 * (2150.0 - y),
+        _ => -20.0 + 32.0 * u * u,
+    }
+}
+
+/// julian ephemeris day of a Datetime in universal time
+pub(crate) fn to_jde(dt: &Datetime) -> f64 {
+    ephemeris_day(dt.to_julian_day())
+}
+
+/// julian ephemeris day of a julian day in universal time
+pub(crate) fn ephemeris_day(jd: f64) -> f64 {
+    jd + delta_t(decimal_year(jd)) / 86400.0
+}
+
+/// the Datetime in universal time of a julian ephemeris day
+pub(crate) fn from_jde(jde: f64) -> Datetime {
+    let mut dt = Datetime::from_julian_day(jde - delta_t(decimal_year(jde)) / 86400.0);
+    dt.round_subsec(0);
+    dt
+}
+
+/// the year with a fraction of a julian day
+pub(crate) fn decimal_year(jd: f64) -> f64 {
+    2000.0 + (jd - J2000) / 365.2425
+}
@@ -0,0 +1,313 @@
+use std::fmt::Display;
+
+use crate::Datetime;
+
+/// the first lunar year in the table
+const FIRST_YEAR: i64 = 1900;
+
+/// one entry per lunar year from 1900 to 2100, bits 15..4 flag a 30 day month for months 1..12,
+/// bits 3..0 give the leap month and bit 16 flags a 30 day leap month
+const LUNAR_INFO: [u32; 201] = [
+    0x04bd8, 0x04ae0, 0x0a570, 0x054d5, 0x0d260, 0x0d950, 0x16554, 0x056a0, 0x09ad0, 0x055d2,
+    0x04ae0, 0x0a5b6, 0x0a4d0, 0x0d250, 0x1d255, 0x0b540, 0x0d6a0, 0x0ada2, 0x095b0, 0x14977,
+    0x04970, 0x0a4b0, 0x0b4b5, 0x06a50, 0x06d40, 0x1ab54, 0x02b60, 0x09570, 0x052f2, 0x04970,
+    0x06566, 0x0d4a0, 0x0ea50, 0x16a95, 0x05ad0, 0x02b60, 0x186e3, 0x092e0, 0x1c8d7, 0x0c950,
+    0x0d4a0, 0x1d8a6, 0x0b550, 0x056a0, 0x1a5b4, 0x025d0, 0x092d0, 0x0d2b2, 0x0a950, 0x0b557,
+    0x06ca0, 0x0b550, 0x15355, 0x04da0, 0x0a5b0, 0x14573, 0x052b0, 0x0a9a8, 0x0e950, 0x06aa0,
+    0x0aea6, 0x0ab50, 0x04b60, 0x0aae4, 0x0a570, 0x05260, 0x0f263, 0x0d950, 0x05b57, 0x056a0,
+    0x096d0, 0x04dd5, 0x04ad0, 0x0a4d0, 0x0d4d4, 0x0d250, 0x0d558, 0x0b540, 0x0b6a0, 0x195a6,
+    0x095b0, 0x049b0, 0x0a974, 0x0a4b0, 0x0b27a, 0x06a50, 0x06d40, 0x0af46, 0x0ab60, 0x09570,
+    0x04af5, 0x04970, 0x064b0, 0x074a3, 0x0ea50, 0x06b58, 0x05ac0, 0x0ab60, 0x096d5, 0x092e0,
+    0x0c960, 0x0d954, 0x0d4a0, 0x0da50, 0x07552, 0x056a0, 0x0abb7, 0x025d0, 0x092d0, 0x0cab5,
+    0x0a950, 0x0b4a0, 0x0baa4, 0x0ad50, 0x055d9, 0x04ba0, 0x0a5b0, 0x15176, 0x052b0, 0x0a930,
+    0x07954, 0x06aa0, 0x0ad50, 0x05b52, 0x04b60, 0x0a6e6, 0x0a4e0, 0x0d260, 0x0ea65, 0x0d530,
+    0x05aa0, 0x076a3, 0x096d0, 0x04afb, 0x04ad0, 0x0a4d0, 0x1d0b6, 0x0d250, 0x0d520, 0x0dd45,
+    0x0b5a0, 0x056d0, 0x055b2, 0x049b0, 0x0a577, 0x0a4b0, 0x0aa50, 0x1b255, 0x06d20, 0x0ada0,
+    0x14b63, 0x09370, 0x049f8, 0x04970, 0x064b0, 0x168a6, 0x0ea50, 0x06b20, 0x1a6c4, 0x0aae0,
+    0x092e0, 0x0d2e3, 0x0c960, 0x0d557, 0x0d4a0, 0x0da50, 0x05d55, 0x056a0, 0x0a6d0, 0x055d4,
+    0x052d0, 0x0a9b8, 0x0a950, 0x0b4a0, 0x0b6a6, 0x0ad50, 0x055a0, 0x0aba4, 0x0a5b0, 0x052b0,
+    0x0b273, 0x06930, 0x07337, 0x06aa0, 0x0ad50, 0x14b55, 0x04b60, 0x0a570, 0x054e4, 0x0d160,
+    0x0e968, 0x0d520, 0x0daa0, 0x16aa6, 0x056d0, 0x04ae0, 0x0a9d4, 0x0a2d0, 0x0d150, 0x0f252,
+    0x0d520,
+];
+
+const STEMS: [&str; 10] = ["甲", "乙", "丙", "丁", "戊", "己", "庚", "辛", "壬", "癸"];
+
+const BRANCHES: [&str; 12] = [
+    "子", "丑", "寅", "卯", "辰", "巳", "午", "未", "申", "酉", "戌", "亥",
+];
+
+const ZODIAC: [&str; 12] = [
+    "鼠", "牛", "虎", "兔", "龙", "蛇", "马", "羊", "猴", "鸡", "狗", "猪",
+];
+
+const MONTHS: [&str; 12] = [
+    "正", "二", "三", "四", "五", "六", "七", "八", "九", "十", "冬", "腊",
+];
+
+const DIGITS: [&str; 11] = [
+    "〇", "一", "二", "三", "四", "五", "六", "七", "八", "九", "十",
+];
+
+/// a date of the chinese lunisolar calendar (农历) between 1900-01-31 and 2101-01-28,
+/// `year` is the gregorian year in which the lunar year begins
+/// ```
+/// # use sys_datetime::{ChineseDate, Datetime};
+/// let dt = Datetime::from_str("2024-02-10 00:00:00").unwrap();
+/// let cd = ChineseDate::from_datetime(&dt).unwrap();
+/// assert_eq!(cd.to_string(), "甲辰年正月初一");
+/// assert_eq!(cd.zodiac(), "龙");
+/// assert_eq!(cd.to_datetime(), dt);
+///
+/// let cd = ChineseDate::from_str("2023年闰二月廿九").unwrap();
+/// assert!(cd.is_leap_month());
+/// assert_eq!(cd.to_datetime().to_string(), "2023-04-19 00:00:00");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChineseDate {
+    year: i64,
+    month: u8,
+    day: u8,
+    leap: bool,
+}
+
+impl ChineseDate {
+    /// none if the date is outside the table or does not exist
+    pub fn new(year: i64, month: u8, day: u8, leap: bool) -> Option<Self> {
+        let info = year_info(year)?;
+        if !(1..=12).contains(&month) || day < 1 {
+            return None;
+        }
+        let days = if leap {
+            if info & 0xf != month as u32 {
+                return None;
+            }
+            leap_days(info)
+        } else {
+            month_days(info, month)
+        };
+        if day as i64 > days {
+            return None;
+        }
+        Some(Self {
+            year,
+            month,
+            day,
+            leap,
+        })
+    }
+
+    #[inline(always)]
+    pub fn year(&self) -> i64 {
+        self.year
+    }
+
+    #[inline(always)]
+    pub fn month(&self) -> i64 {
+        self.month as i64
+    }
+
+    #[inline(always)]
+    pub fn day(&self) -> i64 {
+        self.day as i64
+    }
+
+    /// whether the month is the intercalary month (闰月) following `month`
+    #[inline(always)]
+    pub fn is_leap_month(&self) -> bool {
+        self.leap
+    }
+
+    /// the lunar date of the day of `dt`, the time of day is ignored,
+    /// none outside 1900-01-31 to 2101-01-28
+    pub fn from_datetime(dt: &Datetime) -> Option<Self> {
+        if dt.is_infinite() {
+            return None;
+        }
+        let mut offset = dt.unix_days() - first_day();
+        if offset < 0 {
+            return None;
+        }
+        let mut year = FIRST_YEAR;
+        loop {
+            let days = year_days(year_info(year)?);
+            if offset < days {
+                break;
+            }
+            offset -= days;
+            year += 1;
+        }
+        let info = year_info(year)?;
+        for month in 1..=12 {
+            let days = month_days(info, month);
+            if offset < days {
+                return Some(Self {
+                    year,
+                    month,
+                    day: offset as u8 + 1,
+                    leap: false,
+                });
+            }
+            offset -= days;
+            if info & 0xf == month as u32 {
+                let days = leap_days(info);
+                if offset < days {
+                    return Some(Self {
+                        year,
+                        month,
+                        day: offset as u8 + 1,
+                        leap: true,
+                    });
+                }
+                offset -= days;
+            }
+        }
+        None
+    }
+
+    /// midnight of this day in the gregorian calendar
+    pub fn to_datetime(&self) -> Datetime {
+        let mut days = first_day();
+        for year in FIRST_YEAR..self.year {
+            days += year_info(year).map_or(0, year_days);
+        }
+        let info = year_info(self.year).unwrap_or_default();
+        for month in 1..self.month {
+            days += month_days(info, month);
+            if info & 0xf == month as u32 {
+                days += leap_days(info);
+            }
+        }
+        if self.leap {
+            days += month_days(info, self.month);
+        }
+        Datetime::from_unix_days(days + self.day as i64 - 1)
+    }
+
+    /// the sexagenary name of the year such as "甲辰"
+    pub fn stem_branch(&self) -> String {
+        format!(
+            "{}{}",
+            STEMS[(self.year - 4).rem_euclid(10) as usize],
+            BRANCHES[(self.year - 4).rem_euclid(12) as usize]
+        )
+    }
+
+    /// the zodiac animal of the year such as "龙"
+    pub fn zodiac(&self) -> &'static str {
+        ZODIAC[(self.year - 4).rem_euclid(12) as usize]
+    }
+
+    /// the name of the month such as "正月" or "闰二月"
+    pub fn month_name(&self) -> String {
+        format!(
+            "{}{}月",
+            if self.leap { "闰" } else { "" },
+            MONTHS[self.month as usize - 1]
+        )
+    }
+
+    /// the name of the day such as "初一", "廿九" or "三十"
+    pub fn day_name(&self) -> String {
+        match self.day {
+            1..=10 => format!("初{}", DIGITS[self.day as usize]),
+            11..=19 => format!("十{}", DIGITS[self.day as usize - 10]),
+            20 => "二十".to_string(),
+            21..=29 => format!("廿{}", DIGITS[self.day as usize - 20]),
+            _ => "三十".to_string(),
+        }
+    }
+
+    /// create from "2024年正月初一", "二〇二三年闰二月廿九" or "二零二三年闰二月廿九", months may also
+    /// be written "一月", "十一月" and "十二月". The sexagenary year of Display repeats every
+    /// 60 years, so "甲辰年正月初一" does not parse
+    /// ```
+    /// # use sys_datetime::ChineseDate;
+    /// let cd = ChineseDate::from_str("二零二三年闰二月廿九").unwrap();
+    /// assert_eq!(ChineseDate::from_str("二〇二三年闰二月廿九"), Some(cd));
+    /// assert_eq!(cd.to_string(), "癸卯年闰二月廿九");
+    /// assert_eq!(ChineseDate::from_str(&cd.to_string()), None);
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        let (year, rest) = s.trim().split_once('年')?;
+        let year = if year.chars().all(|c| c.is_ascii_digit()) {
+            year.parse().ok()?
+        } else {
+            year.chars().try_fold(0i64, |n, c| {
+                let d = match c {
+                    '零' => 0,
+                    _ => DIGITS[..10].iter().position(|d| d.chars().eq([c]))?,
+                };
+                n.checked_mul(10)?.checked_add(d as i64)
+            })?
+        };
+        let (month, day) = rest.split_once('月')?;
+        let (leap, month) = match month.strip_prefix('闰') {
+            Some(month) => (true, month),
+            None => (false, month),
+        };
+        let month = match month {
+            "十一" => 11,
+            "十二" => 12,
+            "一" => 1,
+            _ => MONTHS.iter().position(|m| *m == month)? as u8 + 1,
+        };
+        let day = (1..=30u8).find(|&d| {
+            Self {
+                year,
+                month,
+                day: d,
+                leap,
+            }
+            .day_name()
+                == day
+        })?;
+        Self::new(year, month, day, leap)
+    }
+}
+
+impl Display for ChineseDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}年{}{}",
+            self.stem_branch(),
+            self.month_name(),
+            self.day_name()
+        )
+    }
+}
+
+/// unix days of the first day of the table, 1900-01-31
+fn first_day() -> i64 {
+    Datetime::from_ymd(1900, 1, 31).unix_days()
+}
+
+fn year_info(year: i64) -> Option<u32> {
+    LUNAR_INFO
+        .get(usize::try_from(year - FIRST_YEAR).ok()?)
+        .copied()
+}
+
+fn month_days(info: u32, month: u8) -> i64 {
+    if info & (0x10000 >> month) != 0 {
+        30
+    } else {
+        29
+    }
+}
+
+fn leap_days(info: u32) -> i64 {
+    if info & 0xf == 0 {
+        0
+    } else if info & 0x10000 != 0 {
+        30
+    } else {
+        29
+    }
+}
+
+fn year_days(info: u32) -> i64 {
+    (1..=12).map(|m| month_days(info, m)).sum::<i64>() + leap_days(info)
+}
@@ -0,0 +1,228 @@
+use crate::Datetime;
+
+/// the unit an epoch based timestamp counts in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EpochUnit {
+    Days,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    /// 100 nanoseconds, as used by windows FILETIME and .NET ticks
+    Ticks,
+    Nanoseconds,
+}
+
+impl EpochUnit {
+    fn nanos(self) -> i128 {
+        match self {
+            EpochUnit::Days => 86_400_000_000_000,
+            EpochUnit::Seconds => 1_000_000_000,
+            EpochUnit::Milliseconds => 1_000_000,
+            EpochUnit::Microseconds => 1_000,
+            EpochUnit::Ticks => 100,
+            EpochUnit::Nanoseconds => 1,
+        }
+    }
+}
+
+/// a timestamp format counting units since an origin
+/// ```
+/// # use sys_datetime::{Datetime, Epoch, EpochUnit};
+/// // GPS receivers of one vendor count milliseconds since 2000-01-01
+/// let epoch = Epoch::new(Datetime::from_rfc3339("2000-01-01").unwrap(), EpochUnit::Milliseconds);
+/// let dt = epoch.to_datetime(86_400_500).unwrap();
+/// assert_eq!(dt.to_string(), "2000-01-02 00:00:00.5");
+/// assert_eq!(epoch.count(&dt), Some(86_400_500));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Epoch {
+    origin: Datetime,
+    unit: EpochUnit,
+}
+
+impl Epoch {
+    /// seconds since 1970-01-01
+    pub const UNIX: Epoch = Epoch::new(Datetime::from_ymd(1970, 1, 1), EpochUnit::Seconds);
+    /// windows FILETIME, 100 nanosecond ticks since 1601-01-01
+    pub const FILETIME: Epoch = Epoch::new(Datetime::from_ymd(1601, 1, 1), EpochUnit::Ticks);
+    /// apple cocoa / core data, seconds since 2001-01-01
+    pub const COCOA: Epoch = Epoch::new(Datetime::from_ymd(2001, 1, 1), EpochUnit::Seconds);
+    /// HFS+ volumes, seconds since 1904-01-01
+    pub const HFS_PLUS: Epoch = Epoch::new(Datetime::from_ymd(1904, 1, 1), EpochUnit::Seconds);
+    /// chrome / webkit, microseconds since 1601-01-01
+    pub const WEBKIT: Epoch = Epoch::new(Datetime::from_ymd(1601, 1, 1), EpochUnit::Microseconds);
+    /// postgres, microseconds since 2000-01-01
+    pub const POSTGRES: Epoch = Epoch::new(Datetime::from_ymd(2000, 1, 1), EpochUnit::Microseconds);
+
+    pub const fn new(origin: Datetime, unit: EpochUnit) -> Self {
+        Self { origin, unit }
+    }
+
+    #[inline(always)]
+    pub fn origin(&self) -> Datetime {
+        self.origin
+    }
+
+    #[inline(always)]
+    pub fn unit(&self) -> EpochUnit {
+        self.unit
+    }
+
+    /// the Datetime that is `count` units after the origin,
+    /// none if its seconds since 1970-01-01 do not fit in an i64
+    /// ```
+    /// # use sys_datetime::{Epoch, EpochUnit};
+    /// let epoch = Epoch::new(Epoch::UNIX.origin(), EpochUnit::Days);
+    /// assert_eq!(epoch.to_datetime(1).unwrap().to_string(), "1970-01-02 00:00:00");
+    /// assert_eq!(epoch.to_datetime(i64::MAX), None);
+    /// assert_eq!(Epoch::COCOA.to_datetime(i64::MAX), None);
+    /// ```
+    pub fn to_datetime(&self, count: i64) -> Option<Datetime> {
+        self.checked_datetime_at(count as i128 * self.unit.nanos())
+    }
+
+    /// the number of whole units from the origin to `dt`, rounded towards the past,
+    /// none if it does not fit in an i64 or `dt` is INFINITY or NEG_INFINITY
+    pub fn count(&self, dt: &Datetime) -> Option<i64> {
+        if dt.is_infinite() {
+            return None;
+        }
+        i64::try_from(self.nanos(dt).div_euclid(self.unit.nanos())).ok()
+    }
+
+    /// nanoseconds from the origin to `dt`
+    fn nanos(&self, dt: &Datetime) -> i128 {
+        (dt.unix_seconds() as i128 - self.origin.unix_seconds() as i128) * 1_000_000_000
+            + dt.nanosecond as i128
+            - self.origin.nanosecond as i128
+    }
+
+    /// the Datetime that is `nanos` nanoseconds after the origin,
+    /// none if its seconds since 1970-01-01 do not fit in an i64
+    fn checked_datetime_at(&self, nanos: i128) -> Option<Datetime> {
+        let seconds = nanos
+            .checked_add(self.origin.nanosecond as i128)?
+            .div_euclid(1_000_000_000);
+        self.origin
+            .unix_seconds()
+            .checked_add(i64::try_from(seconds).ok()?)?;
+        Some(self.datetime_at(nanos))
+    }
+
+    /// the Datetime that is `nanos` nanoseconds after the origin, the seconds must fit in an i64
+    fn datetime_at(&self, nanos: i128) -> Datetime {
+        let nanos = nanos + self.origin.nanosecond as i128;
+        let mut dt = Datetime::from_unix_seconds(
+            self.origin.unix_seconds() + nanos.div_euclid(1_000_000_000) as i64,
+        );
+        dt.nanosecond = nanos.rem_euclid(1_000_000_000) as u32;
+        dt
+    }
+}
+
+impl Datetime {
+    /// create from a windows FILETIME
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// let dt = Datetime::from_filetime(116444736000000000);
+    /// assert_eq!(dt.to_string(), "1970-01-01 00:00:00");
+    /// assert_eq!(dt.to_filetime(), Some(116444736000000000));
+    /// ```
+    pub fn from_filetime(filetime: u64) -> Self {
+        Epoch::FILETIME.datetime_at(filetime as i128 * 100)
+    }
+
+    /// windows FILETIME, none before 1601-01-01
+    pub fn to_filetime(&self) -> Option<u64> {
+        u64::try_from(Epoch::FILETIME.nanos(self).div_euclid(100)).ok()
+    }
+
+    /// create from an apple cocoa absolute time, none if it is not finite or too far for an i64
+    /// of seconds
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// let dt = Datetime::from_cocoa(-0.25).unwrap();
+    /// assert_eq!(dt.to_string(), "2000-12-31 23:59:59.75");
+    /// assert_eq!(dt.to_cocoa(), -0.25);
+    /// assert_eq!(Datetime::from_cocoa(f64::NAN), None);
+    /// assert_eq!(Datetime::from_cocoa(1e300), None);
+    /// ```
+    pub fn from_cocoa(seconds: f64) -> Option<Self> {
+        if !seconds.is_finite() {
+            return None;
+        }
+        Epoch::COCOA.checked_datetime_at((seconds * 1e9).round() as i128)
+    }
+
+    /// apple cocoa absolute time, seconds since 2001-01-01, infinite for INFINITY and NEG_INFINITY
+    pub fn to_cocoa(&self) -> f64 {
+        if self.is_infinite() {
+            return self.infinity_f64();
+        }
+        Epoch::COCOA.nanos(self) as f64 / 1e9
+    }
+
+    /// create from an HFS+ timestamp
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// assert_eq!(Datetime::from_hfs_plus(2082844800).to_string(), "1970-01-01 00:00:00");
+    /// ```
+    pub fn from_hfs_plus(seconds: u32) -> Self {
+        Epoch::HFS_PLUS.datetime_at(seconds as i128 * 1_000_000_000)
+    }
+
+    /// HFS+ timestamp, none outside 1904-01-01 to 2040-02-06 06:28:15
+    pub fn to_hfs_plus(&self) -> Option<u32> {
+        u32::try_from(Epoch::HFS_PLUS.count(self)?).ok()
+    }
+
+    /// create from a chrome / webkit timestamp
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// let dt = Datetime::from_webkit(13_000_000_000_000_000);
+    /// assert_eq!(dt.to_string(), "2012-12-14 23:06:40");
+    /// assert_eq!(dt.to_webkit(), Some(13_000_000_000_000_000));
+    /// ```
+    pub fn from_webkit(microseconds: i64) -> Self {
+        Epoch::WEBKIT.datetime_at(microseconds as i128 * 1_000)
+    }
+
+    /// chrome / webkit timestamp, microseconds since 1601-01-01
+    pub fn to_webkit(&self) -> Option<i64> {
+        Epoch::WEBKIT.count(self)
+    }
+
+    /// create from packed MS-DOS date and time words, none if they do not form a valid date
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// let dt = Datetime::from_dos(0x5821, 0x6A3D).unwrap();
+    /// assert_eq!(dt.to_string(), "2024-01-01 13:17:58");
+    /// assert_eq!(dt.to_dos(), Some((0x5821, 0x6A3D)));
+    /// ```
+    pub fn from_dos(date: u16, time: u16) -> Option<Self> {
+        let mut dt = Datetime::from_ymd(
+            1980 + (date >> 9) as i64,
+            (date >> 5 & 0x0f) as u8,
+            (date & 0x1f) as u8,
+        );
+        dt.hour = (time >> 11) as u8;
+        dt.minute = (time >> 5 & 0x3f) as u8;
+        dt.second = ((time & 0x1f) * 2) as u8;
+        if dt.is_valid() {
+            Some(dt)
+        } else {
+            None
+        }
+    }
+
+    /// packed MS-DOS (date, time) words with two second resolution,
+    /// none outside 1980-01-01 to 2107-12-31
+    pub fn to_dos(&self) -> Option<(u16, u16)> {
+        if !(1980..=2107).contains(&self.year) {
+            return None;
+        }
+        let date = ((self.year - 1980) as u16) << 9 | (self.month as u16) << 5 | self.day as u16;
+        let time = (self.hour as u16) << 11 | (self.minute as u16) << 5 | (self.second / 2) as u16;
+        Some((date, time))
+    }
+}
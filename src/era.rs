@@ -0,0 +1,175 @@
+use std::fmt::Display;
+
+use crate::Datetime;
+
+/// japanese eras since the adoption of the gregorian calendar and the day each began
+const JAPANESE_ERAS: [(&str, Datetime); 5] = [
+    ("明治", Datetime::from_ymd(1868, 10, 23)),
+    ("大正", Datetime::from_ymd(1912, 7, 30)),
+    ("昭和", Datetime::from_ymd(1926, 12, 25)),
+    ("平成", Datetime::from_ymd(1989, 1, 8)),
+    ("令和", Datetime::from_ymd(2019, 5, 1)),
+];
+
+/// the year 1 of the republic of china
+const MINGUO_FIRST_YEAR: i64 = 1912;
+
+/// the buddhist era counts from 543 BC
+const BUDDHIST_OFFSET: i64 = 543;
+
+/// how years are numbered
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EraSystem {
+    /// japanese imperial eras from 明治 (1868-10-23) to 令和, dates before meiji have no era
+    Japanese,
+    /// years of the republic of china, 民國 from 1912 and 民國前 counting back before it
+    Minguo,
+    /// thai solar calendar, the gregorian year plus 543 with the new year on january 1
+    Buddhist,
+}
+
+/// a Datetime with its year numbered in an era.
+/// Display and from_str put the era in place of the plain year, a prefix such as "令和6" or
+/// "民國113" or a " BE" suffix like the " BC" of Datetime
+/// ```
+/// # use sys_datetime::{Datetime, EraDatetime, EraSystem};
+/// let dt = Datetime::from_str("2019-04-30 12:00:00").unwrap();
+/// let ed = EraDatetime::new(dt, EraSystem::Japanese).unwrap();
+/// assert_eq!((ed.era(), ed.year()), ("平成", 31));
+/// assert_eq!(ed.to_string(), "平成31-04-30 12:00:00");
+///
+/// let ed = EraDatetime::from_str("令和元年5月1日").unwrap();
+/// assert_eq!(ed.datetime().to_string(), "2019-05-01 00:00:00");
+/// assert_eq!(EraDatetime::from_str("平成31年5月1日"), None);
+///
+/// let ed = EraDatetime::from_str("民國113年1月1日").unwrap();
+/// assert_eq!(ed.datetime().to_string(), "2024-01-01 00:00:00");
+/// let dt = Datetime::from_str("1911-10-10 00:00:00").unwrap();
+/// let ed = EraDatetime::new(dt, EraSystem::Minguo).unwrap();
+/// assert_eq!(ed.to_string(), "民國前1-10-10 00:00:00");
+///
+/// let ed = EraDatetime::from_str("2567-01-01 08:30:00 BE").unwrap();
+/// assert_eq!(ed.datetime().to_string(), "2024-01-01 08:30:00");
+/// assert_eq!(ed.to_string(), "2567-01-01 08:30:00 BE");
+/// assert_eq!(EraDatetime::from_str("令和6年5月1日 BE"), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EraDatetime {
+    datetime: Datetime,
+    system: EraSystem,
+}
+
+impl EraDatetime {
+    /// none for a japanese date before meiji, and for INFINITY and NEG_INFINITY
+    pub fn new(datetime: Datetime, system: EraSystem) -> Option<Self> {
+        if datetime.is_infinite() {
+            return None;
+        }
+        if system == EraSystem::Japanese && japanese_era(&datetime).is_none() {
+            return None;
+        }
+        Some(Self { datetime, system })
+    }
+
+    #[inline(always)]
+    pub fn datetime(&self) -> Datetime {
+        self.datetime
+    }
+
+    #[inline(always)]
+    pub fn system(&self) -> EraSystem {
+        self.system
+    }
+
+    /// the name of the era, "民國前" before 1912 and "BE" for the buddhist era
+    pub fn era(&self) -> &'static str {
+        match self.system {
+            EraSystem::Japanese => japanese_era(&self.datetime).map_or("", |(name, _)| name),
+            EraSystem::Minguo if self.yz() < MINGUO_FIRST_YEAR => "民國前",
+            EraSystem::Minguo => "民國",
+            EraSystem::Buddhist => "BE",
+        }
+    }
+
+    /// the year within the era, counted back from 1 for 民國前
+    pub fn year(&self) -> i64 {
+        let yz = self.yz();
+        match self.system {
+            EraSystem::Japanese => {
+                japanese_era(&self.datetime).map_or(0, |(_, start)| yz - start.year() + 1)
+            }
+            EraSystem::Minguo if yz < MINGUO_FIRST_YEAR => MINGUO_FIRST_YEAR - yz,
+            EraSystem::Minguo => yz - MINGUO_FIRST_YEAR + 1,
+            EraSystem::Buddhist => yz + BUDDHIST_OFFSET,
+        }
+    }
+
+    /// create from a date whose year carries an era such as "令和6年5月1日", "令和元年5月1日",
+    /// "民國113-01-01 00:00:00" or "2567-01-01 BE", none if the date lies outside the era
+    /// or carries both an era prefix and the BE suffix
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        let s = s.trim().replacen("元年", "1年", 1);
+        let (system, era, rest) = if let Some(rest) = s.strip_suffix("BE") {
+            if !rest.starts_with(|c: char| c.is_ascii_digit()) {
+                return None;
+            }
+            (EraSystem::Buddhist, "BE", rest)
+        } else if let Some(rest) = s.strip_prefix("民國前").or(s.strip_prefix("民国前")) {
+            (EraSystem::Minguo, "民國前", rest)
+        } else if let Some(rest) = s.strip_prefix("民國").or(s.strip_prefix("民国")) {
+            (EraSystem::Minguo, "民國", rest)
+        } else {
+            let (name, _) = JAPANESE_ERAS.iter().find(|(name, _)| s.starts_with(name))?;
+            (EraSystem::Japanese, *name, &s[name.len()..])
+        };
+        let mut dt = Datetime::from_str(rest)?;
+        let yz = match era {
+            "BE" => dt.year() - BUDDHIST_OFFSET,
+            "民國前" => MINGUO_FIRST_YEAR - dt.year(),
+            "民國" => MINGUO_FIRST_YEAR + dt.year() - 1,
+            _ => JAPANESE_ERAS
+                .iter()
+                .find(|(name, _)| *name == era)
+                .map_or(0, |(_, start)| start.year() + dt.year() - 1),
+        };
+        dt.year = if yz <= 0 { yz - 1 } else { yz };
+        let parsed = Self::new(dt, system)?;
+        if dt.is_valid() && parsed.era() == era {
+            Some(parsed)
+        } else {
+            None
+        }
+    }
+
+    /// the astronomical year, 0 is 1 BC
+    fn yz(&self) -> i64 {
+        if self.datetime.year < 0 {
+            self.datetime.year + 1
+        } else {
+            self.datetime.year
+        }
+    }
+}
+
+impl Display for EraDatetime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = self.datetime.to_string();
+        let rest = s.trim_end_matches(" BC");
+        let rest = &rest[rest.find('-').unwrap_or(0)..];
+        match self.system {
+            EraSystem::Buddhist => write!(f, "{:0>4}{} BE", self.year(), rest),
+            _ => write!(f, "{}{}{}", self.era(), self.year(), rest),
+        }
+    }
+}
+
+/// the japanese era of a date and the day it began
+fn japanese_era(dt: &Datetime) -> Option<(&'static str, Datetime)> {
+    let days = dt.unix_days();
+    JAPANESE_ERAS
+        .iter()
+        .rev()
+        .find(|(_, start)| start.unix_days() <= days)
+        .copied()
+}
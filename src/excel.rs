@@ -0,0 +1,100 @@
+use crate::Datetime;
+
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+/// the date system a spreadsheet serial number counts days in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DateSystem {
+    /// serial 1 is 1900-01-01 and serial 60 the fictitious 1900-02-29 kept for Lotus 1-2-3
+    Excel1900,
+    /// serial 0 is 1904-01-01, the default of old Mac versions of Excel
+    Excel1904,
+    /// serial 0 is 1899-12-30 and negative serials count back into the past,
+    /// as LibreOffice and OLE automation dates do
+    LibreOffice,
+}
+
+impl DateSystem {
+    /// the day serial 0 stands for, and the smallest and largest serial day accepted
+    fn bounds(self) -> (Datetime, i64, i64) {
+        match self {
+            DateSystem::Excel1900 => (Datetime::from_ymd(1899, 12, 31), 0, 2958465),
+            DateSystem::Excel1904 => (Datetime::from_ymd(1904, 1, 1), 0, 2957003),
+            DateSystem::LibreOffice => (Datetime::from_ymd(1899, 12, 30), i64::MIN, i64::MAX),
+        }
+    }
+}
+
+impl Datetime {
+    /// create from a spreadsheet serial number, the fraction is the time of day rounded to
+    /// microseconds, use [`Datetime::round_subsec`] for a coarser resolution.
+    /// Excel serial 60 gives the fictitious 1900-02-29 which is not [`Datetime::is_valid`]
+    /// ```
+    /// # use sys_datetime::{Datetime, DateSystem};
+    /// let dt = Datetime::from_excel_serial(45292.5, DateSystem::Excel1900).unwrap();
+    /// assert_eq!(dt.to_string(), "2024-01-01 12:00:00");
+    ///
+    /// let dt = Datetime::from_excel_serial(60.0, DateSystem::Excel1900).unwrap();
+    /// assert_eq!(dt.to_string(), "1900-02-29 00:00:00");
+    /// assert_eq!(dt.to_excel_serial(DateSystem::Excel1900), Some(60.0));
+    ///
+    /// let mut dt = Datetime::from_excel_serial(0.1, DateSystem::Excel1904).unwrap();
+    /// assert_eq!(dt.round_subsec(0).to_string(), "1904-01-01 02:24:00");
+    /// ```
+    pub fn from_excel_serial(serial: f64, system: DateSystem) -> Option<Self> {
+        if !serial.is_finite() {
+            return None;
+        }
+        let micros = (serial * MICROS_PER_DAY as f64).round() as i64;
+        let days = micros.div_euclid(MICROS_PER_DAY);
+        let (origin, min, max) = system.bounds();
+        if days < min || days > max {
+            return None;
+        }
+
+        let mut dt = if system == DateSystem::Excel1900 && days >= 60 {
+            if days == 60 {
+                Datetime::from_ymd(1900, 2, 29)
+            } else {
+                Self::from_unix_days(origin.unix_days() + days - 1)
+            }
+        } else {
+            Self::from_unix_days(origin.unix_days() + days)
+        };
+        let micros = micros.rem_euclid(MICROS_PER_DAY);
+        dt.hour = (micros / 3_600_000_000) as u8;
+        dt.minute = (micros / 60_000_000 % 60) as u8;
+        dt.second = (micros / 1_000_000 % 60) as u8;
+        dt.nanosecond = (micros % 1_000_000 * 1000) as u32;
+        Some(dt)
+    }
+
+    /// spreadsheet serial number, none before the first day of the date system or after the last
+    pub fn to_excel_serial(&self, system: DateSystem) -> Option<f64> {
+        if self.is_infinite() {
+            return None;
+        }
+        let (origin, min, max) = system.bounds();
+        let days = if system == DateSystem::Excel1900
+            && self.year == 1900
+            && self.month == 2
+            && self.day == 29
+        {
+            60
+        } else {
+            let days = self.unix_days() - origin.unix_days();
+            if system == DateSystem::Excel1900 && days >= 60 {
+                days + 1
+            } else {
+                days
+            }
+        };
+        if days < min || days > max {
+            return None;
+        }
+
+        let nanos = ((self.hour() * 60 + self.minute()) * 60 + self.second()) * 1_000_000_000
+            + self.nanosecond();
+        Some(days as f64 + nanos as f64 / (MICROS_PER_DAY as f64 * 1000.0))
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{leap::leap_second_table, offset_count, Datetime};
+
+/// unix seconds of the gps epoch 1980-01-06 00:00:00 UTC
+const GPS_EPOCH: i64 = 315964800;
+
+/// TAI - GPS, fixed when gps time was aligned with UTC in 1980
+const TAI_GPS: i64 = 19;
+
+const SECONDS_PER_WEEK: i64 = 604800;
+
+/// legacy navigation messages carry the week number modulo 1024
+pub const GPS_WEEK_ROLLOVER: i64 = 1024;
+
+impl Datetime {
+    /// seconds since the gps epoch 1980-01-06 00:00:00, leap seconds included,
+    /// i64::MAX for INFINITY and i64::MIN for NEG_INFINITY
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// let dt = Datetime::from_rfc3339("2017-01-01 00:00:00").unwrap();
+    /// assert_eq!(dt.gps_seconds(), 1167264018);
+    /// ```
+    pub fn gps_seconds(&self) -> i64 {
+        offset_count(self.unix_seconds(), self.tai_offset() - TAI_GPS - GPS_EPOCH)
+    }
+
+    /// create from seconds since the gps epoch,
+    /// an inserted leap second 23:59:60 is reported as 23:59:59
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// assert_eq!(
+    ///     Datetime::from_gps_seconds(1167264018).to_string(),
+    ///     "2017-01-01 00:00:00"
+    /// );
+    /// assert_eq!(
+    ///     Datetime::from_gps_seconds(1167264017).to_string(),
+    ///     "2016-12-31 23:59:59"
+    /// );
+    /// ```
+    pub fn from_gps_seconds(seconds: i64) -> Self {
+        let offset = leap_second_table()
+            .take_while(|&(start, offset)| start - GPS_EPOCH + offset - TAI_GPS - 1 <= seconds)
+            .last()
+            .map_or(10, |(_, offset)| offset);
+        Self::from_unix_seconds(seconds + GPS_EPOCH - offset + TAI_GPS)
+    }
+
+    /// the full gps week number and the seconds into that week
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// let dt = Datetime::from_rfc3339("2019-04-07 00:00:00").unwrap();
+    /// assert_eq!(dt.gps_week(), (2048, 18));
+    /// ```
+    pub fn gps_week(&self) -> (i64, i64) {
+        let s = self.gps_seconds();
+        (
+            s.div_euclid(SECONDS_PER_WEEK),
+            s.rem_euclid(SECONDS_PER_WEEK),
+        )
+    }
+
+    /// create from a full gps week number and the seconds into that week
+    pub fn from_gps_week(week: i64, tow: i64) -> Self {
+        Self::from_gps_seconds(week * SECONDS_PER_WEEK + tow)
+    }
+
+    /// create from a week number that may have rolled over every 1024 weeks,
+    /// the week closest to the week of `reference` is chosen, an infinite reference is returned
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// let reference = Datetime::from_rfc3339("2024-01-01").unwrap();
+    /// assert_eq!(
+    ///     Datetime::from_gps_week_rollover(0, 18, reference).to_string(),
+    ///     "2019-04-07 00:00:00"
+    /// );
+    /// ```
+    pub fn from_gps_week_rollover(week: i64, tow: i64, reference: Datetime) -> Self {
+        if reference.is_infinite() {
+            return reference;
+        }
+        let (reference_week, _) = reference.gps_week();
+        let mut full = reference_week
+            - (reference_week - week.rem_euclid(GPS_WEEK_ROLLOVER)).rem_euclid(GPS_WEEK_ROLLOVER);
+        if reference_week - full > GPS_WEEK_ROLLOVER / 2 {
+            full += GPS_WEEK_ROLLOVER;
+        }
+        Self::from_gps_week(full, tow)
+    }
+}
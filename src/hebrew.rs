@@ -0,0 +1,248 @@
+use std::fmt::Display;
+
+use crate::Datetime;
+
+/// unix days of 1 Tishrei AM 1, 3761-10-07 BC (julian)
+const EPOCH: i64 = -1373427 - 719163;
+
+const NISAN: u8 = 1;
+const ADAR: u8 = 12;
+const ADAR_II: u8 = 13;
+const TISHREI: u8 = 7;
+
+const MONTHS: [&str; 13] = [
+    "Nisan", "Iyyar", "Sivan", "Tammuz", "Av", "Elul", "Tishrei", "Cheshvan", "Kislev", "Tevet",
+    "Shevat", "Adar", "Adar II",
+];
+
+/// a date of the hebrew calendar. Months are numbered from Nisan = 1 as in the bible, so the
+/// year (AM) begins with Tishrei = 7; Adar is 12, which is Adar I in a leap year, and Adar II is 13
+/// ```
+/// # use sys_datetime::{Datetime, HebrewDate};
+/// let dt = Datetime::from_str("2023-09-16 00:00:00").unwrap();
+/// let hd = HebrewDate::from_datetime(&dt).unwrap();
+/// assert_eq!(hd.to_string(), "1 Tishrei 5784");
+/// assert_eq!(hd.to_datetime(), dt);
+///
+/// let hd = HebrewDate::new(5784, 13, 14).unwrap();
+/// assert_eq!(hd.to_string(), "14 Adar II 5784");
+/// assert_eq!(hd.to_datetime().to_string(), "2024-03-24 00:00:00");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HebrewDate {
+    year: i64,
+    month: u8,
+    day: u8,
+}
+
+impl HebrewDate {
+    /// none if the date does not exist
+    pub fn new(year: i64, month: u8, day: u8) -> Option<Self> {
+        if month < 1 || month > last_month(year) || day < 1 || day > month_days(year, month) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    #[inline(always)]
+    pub fn year(&self) -> i64 {
+        self.year
+    }
+
+    #[inline(always)]
+    pub fn month(&self) -> i64 {
+        self.month as i64
+    }
+
+    #[inline(always)]
+    pub fn day(&self) -> i64 {
+        self.day as i64
+    }
+
+    /// whether the year has the 13th month Adar II, 7 years in every 19
+    pub fn is_leap_year(year: i64) -> bool {
+        (7 * year + 1).rem_euclid(19) < 7
+    }
+
+    /// the number of days in the year, 353 to 355 or 383 to 385
+    pub fn year_days(year: i64) -> i64 {
+        new_year(year + 1) - new_year(year)
+    }
+
+    /// the hebrew date of the day of `dt`, the time of day is ignored.
+    /// A hebrew day starts at sunset, so the evening belongs to the next hebrew day.
+    /// none for INFINITY and NEG_INFINITY
+    pub fn from_datetime(dt: &Datetime) -> Option<Self> {
+        if dt.is_infinite() {
+            return None;
+        }
+        let days = dt.unix_days();
+        // mean year of the metonic cycle, the estimate is at most one year early
+        let mut year = ((days - EPOCH) as f64 / (35975351.0 / 98496.0)).floor() as i64;
+        while new_year(year + 1) <= days {
+            year += 1;
+        }
+        let mut month = if days < unix_days(year, NISAN, 1) {
+            TISHREI
+        } else {
+            NISAN
+        };
+        while days > unix_days(year, month, month_days(year, month)) {
+            month = month % last_month(year) + 1;
+        }
+        Some(Self {
+            year,
+            month,
+            day: (days - unix_days(year, month, 1) + 1) as u8,
+        })
+    }
+
+    /// midnight of this day in the gregorian calendar
+    pub fn to_datetime(&self) -> Datetime {
+        Datetime::from_unix_days(unix_days(self.year, self.month, self.day))
+    }
+
+    /// the english name of the month such as "Tishrei" or "Adar I"
+    pub fn month_name(&self) -> &'static str {
+        if self.month == ADAR && Self::is_leap_year(self.year) {
+            "Adar I"
+        } else {
+            MONTHS[self.month as usize - 1]
+        }
+    }
+}
+
+impl Display for HebrewDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.day, self.month_name(), self.year)
+    }
+}
+
+/// major jewish holidays, each given by its first day
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HebrewHoliday {
+    /// 1 Tishrei
+    RoshHashanah,
+    /// 10 Tishrei
+    YomKippur,
+    /// 15 Tishrei
+    Sukkot,
+    /// 22 Tishrei
+    SheminiAtzeret,
+    /// 25 Kislev
+    Hanukkah,
+    /// 14 Adar, 14 Adar II in a leap year
+    Purim,
+    /// 15 Nisan
+    Passover,
+    /// 6 Sivan
+    Shavuot,
+}
+
+impl HebrewHoliday {
+    /// the first day of the holiday in a hebrew year (AM)
+    /// ```
+    /// # use sys_datetime::HebrewHoliday;
+    /// let pesach = HebrewHoliday::Passover.in_hebrew_year(5784);
+    /// assert_eq!(pesach.to_datetime().to_string(), "2024-04-23 00:00:00");
+    /// ```
+    pub fn in_hebrew_year(&self, year: i64) -> HebrewDate {
+        let (month, day) = match self {
+            HebrewHoliday::RoshHashanah => (TISHREI, 1),
+            HebrewHoliday::YomKippur => (TISHREI, 10),
+            HebrewHoliday::Sukkot => (TISHREI, 15),
+            HebrewHoliday::SheminiAtzeret => (TISHREI, 22),
+            HebrewHoliday::Hanukkah => (9, 25),
+            HebrewHoliday::Purim => (last_month(year), 14),
+            HebrewHoliday::Passover => (NISAN, 15),
+            HebrewHoliday::Shavuot => (3, 6),
+        };
+        HebrewDate { year, month, day }
+    }
+
+    /// the first day of the holiday in a gregorian year; like every hebrew date it begins
+    /// at sunset of the day before
+    /// ```
+    /// # use sys_datetime::HebrewHoliday;
+    /// assert_eq!(
+    ///     HebrewHoliday::RoshHashanah.in_gregorian_year(2024).to_string(),
+    ///     "2024-10-03 00:00:00"
+    /// );
+    /// ```
+    pub fn in_gregorian_year(&self, year: i64) -> Datetime {
+        let yz = if year < 0 { year + 1 } else { year };
+        let first = self.in_hebrew_year(yz + 3760).to_datetime();
+        if first.year() == year {
+            first
+        } else {
+            self.in_hebrew_year(yz + 3761).to_datetime()
+        }
+    }
+}
+
+/// Adar in a common year, Adar II in a leap year
+fn last_month(year: i64) -> u8 {
+    if HebrewDate::is_leap_year(year) {
+        ADAR_II
+    } else {
+        ADAR
+    }
+}
+
+fn month_days(year: i64, month: u8) -> u8 {
+    let year_days = HebrewDate::year_days(year);
+    match month {
+        2 | 4 | 6 | 10 | ADAR_II => 29,
+        ADAR if !HebrewDate::is_leap_year(year) => 29,
+        // cheshvan is long in a complete year, kislev is short in a deficient year
+        8 if year_days % 10 != 5 => 29,
+        9 if year_days % 10 == 3 => 29,
+        _ => 30,
+    }
+}
+
+/// days from the epoch to the molad of Tishrei, postponed by the rules of dehiyyot
+fn elapsed_days(year: i64) -> i64 {
+    let months = (235 * year - 234).div_euclid(19);
+    let parts = 12084 + 13753 * months;
+    let days = 29 * months + parts.div_euclid(25920);
+    // the new year may not fall on sunday, wednesday or friday
+    if (3 * (days + 1)).rem_euclid(7) < 3 {
+        days + 1
+    } else {
+        days
+    }
+}
+
+/// unix days of 1 Tishrei
+fn new_year(year: i64) -> i64 {
+    let ny0 = elapsed_days(year - 1);
+    let ny1 = elapsed_days(year);
+    let ny2 = elapsed_days(year + 1);
+    // keep the year between 353 and 385 days
+    let correction = if ny2 - ny1 == 356 {
+        2
+    } else if ny1 - ny0 == 382 {
+        1
+    } else {
+        0
+    };
+    EPOCH + ny1 + correction
+}
+
+fn unix_days(year: i64, month: u8, day: u8) -> i64 {
+    let mut days = new_year(year) + day as i64 - 1;
+    if month < TISHREI {
+        for m in TISHREI..=last_month(year) {
+            days += month_days(year, m) as i64;
+        }
+        for m in NISAN..month {
+            days += month_days(year, m) as i64;
+        }
+    } else {
+        for m in TISHREI..month {
+            days += month_days(year, m) as i64;
+        }
+    }
+    days
+}
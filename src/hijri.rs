@@ -0,0 +1,279 @@
+use std::fmt::Display;
+
+use crate::{Datetime, JulianDate};
+
+/// the first year of the umm al-qura table
+const UQ_FIRST_YEAR: i64 = 1300;
+
+/// one entry per year from 1300 to 1600 AH, bit n flags a 30 day month n + 1
+const UMM_AL_QURA: [u16; 301] = [
+    0xaa5, 0x92d, 0x25d, 0x8bd, 0x1ba, 0x5b5, 0x5aa, 0xd4d, 0xa9a, 0x92e, 0x16e, 0x4dd, 0xada,
+    0x6d4, 0x695, 0x52b, 0xa57, 0x52e, 0xaad, 0x5aa, 0xba5, 0xb49, 0xa95, 0x54b, 0xa9b, 0x55a,
+    0xb55, 0xf52, 0xea4, 0xe4a, 0xa95, 0x52d, 0x6ad, 0xb6a, 0x754, 0x749, 0x695, 0xd2b, 0x95a,
+    0x2ba, 0x5b9, 0xbb4, 0xb64, 0xaaa, 0xa56, 0x4b6, 0x96d, 0x2ec, 0x6e9, 0xeb2, 0xd54, 0xcaa,
+    0x93a, 0x2b6, 0x575, 0xb6a, 0xb54, 0xb25, 0xa4b, 0x51b, 0xa5b, 0x2b6, 0x6b5, 0xea9, 0xe92,
+    0xd25, 0xa4d, 0x4ad, 0x95b, 0xb5a, 0x6d2, 0xea5, 0xe4a, 0xc96, 0x536, 0xa75, 0x574, 0xb69,
+    0x752, 0x6a9, 0x555, 0xaad, 0x4ec, 0xaea, 0x5d4, 0xdc9, 0xd52, 0xaa5, 0x4d5, 0x975, 0x4f4,
+    0xae9, 0x6d2, 0x6a5, 0x52b, 0x257, 0x4b7, 0x976, 0x56a, 0xd65, 0xd4a, 0xc96, 0x92e, 0x25d,
+    0x4dd, 0xad6, 0x6aa, 0x695, 0x527, 0xa57, 0x4ae, 0x96d, 0x36a, 0xb65, 0x6c9, 0x693, 0x52b,
+    0x967, 0x2d6, 0x5d5, 0xdd2, 0xba4, 0xb49, 0xa95, 0x52d, 0x5ad, 0xb6a, 0x6e4, 0xdc9, 0xd92,
+    0xaa6, 0x956, 0x2ae, 0x56d, 0x36a, 0xb55, 0xaaa, 0x94d, 0x49d, 0x95d, 0x2ba, 0x5b5, 0x5aa,
+    0xd55, 0xa9a, 0x92e, 0x29e, 0x55d, 0xada, 0x6d4, 0x6a5, 0xd4b, 0xa96, 0x54e, 0xaae, 0x5ac,
+    0xba9, 0xd92, 0xb25, 0x64b, 0xcab, 0x55a, 0xb55, 0x6d2, 0xea5, 0xe4a, 0xa95, 0x52d, 0xaad,
+    0x36c, 0x759, 0x6d2, 0x695, 0x52d, 0xa5b, 0x4ba, 0x9ba, 0x3b4, 0xb69, 0xb52, 0xaa6, 0x4b6,
+    0x96d, 0x2ec, 0x6d9, 0xeb2, 0xd54, 0xd2a, 0xa56, 0x4b6, 0x96d, 0xd6a, 0xb54, 0xb29, 0xa93,
+    0x52b, 0xa57, 0x536, 0xab5, 0x6aa, 0xe93, 0xd26, 0xa4d, 0x4ad, 0x95b, 0xcda, 0x6d4, 0xea9,
+    0xe52, 0xcaa, 0x956, 0xab5, 0x574, 0xb71, 0x764, 0x6c9, 0x555, 0x2ad, 0x56d, 0xaea, 0x5e4,
+    0xdd1, 0xd52, 0xaa5, 0x955, 0x275, 0x4ed, 0xae9, 0x6d2, 0x6a5, 0x94b, 0x457, 0x8b7, 0x2b6,
+    0x575, 0xd6a, 0xd4a, 0xc96, 0x92e, 0x25e, 0x4dd, 0xada, 0x6d2, 0x5a5, 0x54b, 0xa97, 0x4ae,
+    0x96d, 0x36a, 0xb65, 0x752, 0x6a5, 0x54b, 0xaab, 0x55a, 0x6d5, 0xdd2, 0xba4, 0xb4a, 0xa95,
+    0x54d, 0x9ad, 0x36a, 0x5d5, 0x5ca, 0xd95, 0x52a, 0x957, 0x2ae, 0x96e, 0x36c, 0xb55, 0xaaa,
+    0xa55, 0x4ad, 0x15d, 0x2bd, 0x5ba, 0x5aa, 0xd55, 0xaaa, 0x94e, 0x2ae, 0x55d, 0xada, 0x6d4,
+    0x6a9, 0xe8b, 0xd16, 0xa56, 0x2b6, 0x5b5, 0xda9, 0xd92, 0xd45, 0xa8b, 0x52b, 0x55b, 0xb5a,
+    0x6d4, 0xea9,
+];
+
+const MONTHS_EN: [&str; 12] = [
+    "Muharram",
+    "Safar",
+    "Rabi' al-Awwal",
+    "Rabi' al-Thani",
+    "Jumada al-Awwal",
+    "Jumada al-Thani",
+    "Rajab",
+    "Sha'ban",
+    "Ramadan",
+    "Shawwal",
+    "Dhu al-Qi'dah",
+    "Dhu al-Hijjah",
+];
+
+const MONTHS_AR: [&str; 12] = [
+    "محرم",
+    "صفر",
+    "ربيع الأول",
+    "ربيع الآخر",
+    "جمادى الأولى",
+    "جمادى الآخرة",
+    "رجب",
+    "شعبان",
+    "رمضان",
+    "شوال",
+    "ذو القعدة",
+    "ذو الحجة",
+];
+
+/// how the months of the islamic calendar are determined
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HijriCalendar {
+    /// arithmetical calendar with 11 leap years in 30 and the civil epoch 622-07-16 (julian),
+    /// defined for every date
+    Tabular,
+    /// the official calendar of Saudi Arabia, tabulated for 1300-1600 AH
+    /// (1882-11-12 to 2174-11-25)
+    UmmAlQura,
+}
+
+/// a date of the islamic (hijri) calendar
+/// ```
+/// # use sys_datetime::{Datetime, HijriCalendar, HijriDate};
+/// let dt = Datetime::from_str("2024-03-11 00:00:00").unwrap();
+/// let hd = HijriDate::from_datetime(&dt, HijriCalendar::UmmAlQura).unwrap();
+/// assert_eq!(hd.to_string(), "1445-09-01 AH");
+/// assert_eq!(hd.format_en(), "1 Ramadan 1445 AH");
+/// assert_eq!(hd.format_ar(), "١ رمضان ١٤٤٥ هـ");
+/// assert_eq!(hd.to_datetime(), dt);
+///
+/// let dt = Datetime::from_str("2024-07-07 00:00:00").unwrap();
+/// let hd = HijriDate::from_datetime(&dt, HijriCalendar::Tabular).unwrap();
+/// assert_eq!(hd.to_string(), "1445-12-30 AH");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HijriDate {
+    year: i64,
+    month: u8,
+    day: u8,
+    calendar: HijriCalendar,
+}
+
+impl HijriDate {
+    /// none if the date does not exist or is outside the umm al-qura table
+    pub fn new(year: i64, month: u8, day: u8, calendar: HijriCalendar) -> Option<Self> {
+        if !(1..=12).contains(&month) || day < 1 || day as i64 > month_days(year, month, calendar)?
+        {
+            return None;
+        }
+        Some(Self {
+            year,
+            month,
+            day,
+            calendar,
+        })
+    }
+
+    #[inline(always)]
+    pub fn year(&self) -> i64 {
+        self.year
+    }
+
+    #[inline(always)]
+    pub fn month(&self) -> i64 {
+        self.month as i64
+    }
+
+    #[inline(always)]
+    pub fn day(&self) -> i64 {
+        self.day as i64
+    }
+
+    #[inline(always)]
+    pub fn calendar(&self) -> HijriCalendar {
+        self.calendar
+    }
+
+    /// whether the tabular year has 355 days
+    pub fn is_leap_year(year: i64) -> bool {
+        (14 + 11 * year).rem_euclid(30) < 11
+    }
+
+    /// the hijri date of the day of `dt`, the time of day is ignored.
+    /// Years before 1 AH are counted proleptically with 0 and negative numbers.
+    /// None for umm al-qura outside its table
+    pub fn from_datetime(dt: &Datetime, calendar: HijriCalendar) -> Option<Self> {
+        if dt.is_infinite() {
+            return None;
+        }
+        let days = dt.unix_days();
+        let mut year = match calendar {
+            HijriCalendar::Tabular => (30 * (days - civil_epoch()) + 10646).div_euclid(10631),
+            HijriCalendar::UmmAlQura => {
+                if days < uq_epoch() {
+                    return None;
+                }
+                UQ_FIRST_YEAR + (days - uq_epoch()) * 30 / 10631
+            }
+        };
+        while year_start(year + 1, calendar)? <= days {
+            year += 1;
+        }
+        while year_start(year, calendar)? > days {
+            year -= 1;
+        }
+        let mut offset = days - year_start(year, calendar)?;
+        for month in 1..=12 {
+            let len = month_days(year, month, calendar)?;
+            if offset < len {
+                return Some(Self {
+                    year,
+                    month,
+                    day: offset as u8 + 1,
+                    calendar,
+                });
+            }
+            offset -= len;
+        }
+        None
+    }
+
+    /// midnight of this day in the gregorian calendar
+    pub fn to_datetime(&self) -> Datetime {
+        let mut days = year_start(self.year, self.calendar).unwrap_or_default();
+        for month in 1..self.month {
+            days += month_days(self.year, month, self.calendar).unwrap_or_default();
+        }
+        Datetime::from_unix_days(days + self.day as i64 - 1)
+    }
+
+    /// the english name of the month such as "Ramadan"
+    pub fn month_name_en(&self) -> &'static str {
+        MONTHS_EN[self.month as usize - 1]
+    }
+
+    /// the arabic name of the month such as "رمضان"
+    pub fn month_name_ar(&self) -> &'static str {
+        MONTHS_AR[self.month as usize - 1]
+    }
+
+    /// such as "1 Ramadan 1445 AH"
+    pub fn format_en(&self) -> String {
+        format!("{} {} {} AH", self.day, self.month_name_en(), self.year)
+    }
+
+    /// such as "١ رمضان ١٤٤٥ هـ" with arabic-indic digits
+    pub fn format_ar(&self) -> String {
+        format!(
+            "{} {} {} هـ",
+            arabic_digits(self.day as i64),
+            self.month_name_ar(),
+            arabic_digits(self.year)
+        )
+    }
+}
+
+impl Display for HijriDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:0>4}-{:0>2}-{:0>2} AH",
+            self.year, self.month, self.day
+        )
+    }
+}
+
+/// unix days of 1 Muharram 1 AH in the civil tabular calendar, 622-07-16 julian
+fn civil_epoch() -> i64 {
+    JulianDate::new(622, 7, 16).map_or(0, |jd| jd.to_datetime().unix_days())
+}
+
+/// unix days of 1 Muharram 1300 AH, 1882-11-12
+fn uq_epoch() -> i64 {
+    Datetime::from_ymd(1882, 11, 12).unix_days()
+}
+
+fn uq_info(year: i64) -> Option<u16> {
+    UMM_AL_QURA
+        .get(usize::try_from(year - UQ_FIRST_YEAR).ok()?)
+        .copied()
+}
+
+fn month_days(year: i64, month: u8, calendar: HijriCalendar) -> Option<i64> {
+    match calendar {
+        HijriCalendar::Tabular => Some(if month == 12 && HijriDate::is_leap_year(year) {
+            30
+        } else {
+            30 - (month as i64 + 1) % 2
+        }),
+        HijriCalendar::UmmAlQura => Some(29 + (uq_info(year)? >> (month - 1) & 1) as i64),
+    }
+}
+
+/// unix days of 1 Muharram of the year
+fn year_start(year: i64, calendar: HijriCalendar) -> Option<i64> {
+    match calendar {
+        HijriCalendar::Tabular => {
+            Some(civil_epoch() + (year - 1) * 354 + (3 + 11 * year).div_euclid(30))
+        }
+        HijriCalendar::UmmAlQura => {
+            let n = usize::try_from(year - UQ_FIRST_YEAR).ok()?;
+            // the year after the table is accepted as the end of 1600 AH
+            let years = UMM_AL_QURA.get(..n)?;
+            Some(
+                uq_epoch()
+                    + years
+                        .iter()
+                        .map(|info| 348 + info.count_ones() as i64)
+                        .sum::<i64>(),
+            )
+        }
+    }
+}
+
+fn arabic_digits(n: i64) -> String {
+    n.to_string()
+        .chars()
+        .map(|c| match c.to_digit(10) {
+            Some(d) => char::from_u32(0x0660 + d).unwrap_or(c),
+            None => c,
+        })
+        .collect()
+}
@@ -0,0 +1,232 @@
+use std::fmt::Display;
+
+use crate::Datetime;
+
+/// days from the julian 0000-03-01 to 1970-01-01
+const JULIAN_UNIX: i64 = 719470;
+
+/// a date of the julian calendar, a negative year is BC like in Datetime
+/// ```
+/// # use sys_datetime::{Datetime, JulianDate};
+/// let ides = JulianDate::from_str("0044-03-15 BC").unwrap();
+/// assert_eq!(ides.to_datetime().to_string(), "0044-03-13 00:00:00 BC");
+///
+/// let dt = Datetime::from_str("1582-10-15 00:00:00").unwrap();
+/// assert_eq!(JulianDate::from_datetime(&dt).unwrap().to_string(), "1582-10-05");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct JulianDate {
+    year: i64,
+    month: u8,
+    day: u8,
+}
+
+impl JulianDate {
+    /// none if the date does not exist in the julian calendar
+    pub fn new(year: i64, month: u8, day: u8) -> Option<Self> {
+        let dt = Self { year, month, day };
+        if dt.is_valid() {
+            Some(dt)
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    pub fn year(&self) -> i64 {
+        self.year
+    }
+
+    #[inline(always)]
+    pub fn month(&self) -> i64 {
+        self.month as i64
+    }
+
+    #[inline(always)]
+    pub fn day(&self) -> i64 {
+        self.day as i64
+    }
+
+    /// every fourth year is a leap year, 1 BC included
+    pub fn is_leap_year(year: i64) -> bool {
+        let yz = if year < 0 { year + 1 } else { year };
+        yz % 4 == 0
+    }
+
+    /// the julian date of the day of `dt`, the time of day is ignored,
+    /// none for INFINITY and NEG_INFINITY
+    pub fn from_datetime(dt: &Datetime) -> Option<Self> {
+        if dt.is_infinite() {
+            return None;
+        }
+        let z = dt.unix_days() + JULIAN_UNIX;
+        let yoe = (z.rem_euclid(1461) / 365).min(3);
+        let doy = z.rem_euclid(1461) - 365 * yoe;
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let yz = z.div_euclid(1461) * 4 + yoe + if month <= 2 { 1 } else { 0 };
+        Some(Self {
+            year: if yz <= 0 { yz - 1 } else { yz },
+            month: month as u8,
+            day: day as u8,
+        })
+    }
+
+    /// midnight of this day in the proleptic gregorian calendar
+    pub fn to_datetime(&self) -> Datetime {
+        Datetime::from_unix_days(self.unix_days())
+    }
+
+    /// create from "yyyy-mm-dd" with an optional " BC" suffix, none if the date does not exist
+    /// or is written any other way
+    /// ```
+    /// # use sys_datetime::JulianDate;
+    /// assert_eq!(JulianDate::from_str("1700-02-29").unwrap().to_string(), "1700-02-29");
+    /// assert_eq!(JulianDate::from_str("1701-02-29"), None);
+    /// assert_eq!(JulianDate::from_str("infinity"), None);
+    /// assert_eq!(JulianDate::from_str("-infinity"), None);
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        let (date, bc) = match s.strip_suffix(" BC") {
+            Some(date) => (date, true),
+            None => (s, false),
+        };
+        let mut fields = date.split('-');
+        let (year, month, day) = (fields.next()?, fields.next()?, fields.next()?);
+        if fields.next().is_some() || !year.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let year: i64 = year.parse().ok()?;
+        Self::new(
+            if bc { -year } else { year },
+            month.parse().ok()?,
+            day.parse().ok()?,
+        )
+    }
+
+    pub fn is_valid(&self) -> bool {
+        if self.year == 0 || self.month < 1 || self.month > 12 || self.day < 1 {
+            return false;
+        }
+        match self.month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => self.day <= 31,
+            2 if Self::is_leap_year(self.year) => self.day <= 29,
+            2 => self.day <= 28,
+            _ => self.day <= 30,
+        }
+    }
+
+    fn unix_days(&self) -> i64 {
+        let yz = if self.year < 0 {
+            self.year + 1
+        } else {
+            self.year
+        };
+        let month = self.month as i64;
+        let y = if month <= 2 { yz - 1 } else { yz };
+        let doy = (153 * ((month + 9) % 12) + 2) / 5 + self.day as i64 - 1;
+        y.div_euclid(4) * 1461 + y.rem_euclid(4) * 365 + doy - JULIAN_UNIX
+    }
+}
+
+impl Display for JulianDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:0>4}-{:0>2}-{:0>2}",
+            self.year.abs(),
+            self.month,
+            self.day
+        )?;
+        if self.year < 0 {
+            write!(f, " BC")?;
+        }
+        Ok(())
+    }
+}
+
+/// the day a country switched from the julian to the gregorian calendar.
+/// In historical mode a Datetime holds the date as it was written at the time, julian before the
+/// cutover and gregorian from it on, the days skipped by the reform do not exist
+/// ```
+/// # use sys_datetime::{Cutover, Datetime};
+/// let written = Datetime::from_str("1752-09-02 12:00:00").unwrap();
+/// let dt = Cutover::BRITAIN.from_historical(&written).unwrap();
+/// assert_eq!(dt.to_string(), "1752-09-13 12:00:00");
+///
+/// let skipped = Datetime::from_str("1752-09-03 00:00:00").unwrap();
+/// assert_eq!(Cutover::BRITAIN.from_historical(&skipped), None);
+///
+/// let dt = Datetime::from_str("1918-02-13 00:00:00").unwrap();
+/// assert_eq!(Cutover::RUSSIA.to_historical(&dt).to_string(), "1918-01-31 00:00:00");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cutover {
+    first_gregorian: Datetime,
+}
+
+impl Cutover {
+    /// papal states, spain, portugal and poland, 1582-10-04 was followed by 1582-10-15
+    pub const ROME: Cutover = Cutover::new(Datetime::from_ymd(1582, 10, 15));
+    /// great britain and its colonies, 1752-09-02 was followed by 1752-09-14
+    pub const BRITAIN: Cutover = Cutover::new(Datetime::from_ymd(1752, 9, 14));
+    /// soviet russia, 1918-01-31 was followed by 1918-02-14
+    pub const RUSSIA: Cutover = Cutover::new(Datetime::from_ymd(1918, 2, 14));
+
+    /// cutover whose first gregorian day is `first_gregorian`
+    pub const fn new(first_gregorian: Datetime) -> Self {
+        Self { first_gregorian }
+    }
+
+    /// the first day of the gregorian calendar
+    #[inline(always)]
+    pub fn first_gregorian(&self) -> Datetime {
+        self.first_gregorian
+    }
+
+    /// the date as it was written, julian before the cutover, gregorian from it on.
+    /// A julian leap day such as 1700-02-29 is kept although Datetime::is_valid rejects it,
+    /// infinite values are kept too
+    pub fn to_historical(&self, dt: &Datetime) -> Datetime {
+        let jd = match JulianDate::from_datetime(dt) {
+            Some(jd) if dt.unix_days() < self.first_gregorian.unix_days() => jd,
+            _ => return *dt,
+        };
+        let mut historical = *dt;
+        historical.year = jd.year;
+        historical.month = jd.month;
+        historical.day = jd.day;
+        historical
+    }
+
+    /// the proleptic gregorian Datetime of a date as it was written,
+    /// none for the days skipped by the reform or a date that never existed
+    pub fn from_historical(&self, historical: &Datetime) -> Option<Datetime> {
+        let first = self.first_gregorian.unix_days();
+        if historical.is_infinite() || historical.is_valid() && historical.unix_days() >= first {
+            return Some(*historical);
+        }
+        let jd = JulianDate::new(historical.year, historical.month, historical.day)?;
+        let days = jd.unix_days();
+        if days >= first {
+            return None;
+        }
+        let mut dt = *historical;
+        let date = Datetime::from_unix_days(days);
+        dt.year = date.year;
+        dt.month = date.month;
+        dt.day = date.day;
+        if dt.is_valid() {
+            Some(dt)
+        } else {
+            None
+        }
+    }
+
+    /// whether a date as it was written existed
+    pub fn is_valid(&self, historical: &Datetime) -> bool {
+        self.from_historical(historical).is_some()
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{offset_count, Datetime};
+
+/// julian day number of 1970-01-01
+const JDN_UNIX: i64 = 2440588;
+
+/// modified julian day of 1970-01-01
+const MJD_UNIX: i64 = 40587;
+
+/// rata die of 1970-01-01, day 1 is 0001-01-01
+const RD_UNIX: i64 = 719163;
+
+const MICROS_PER_DAY: f64 = 86_400_000_000.0;
+
+impl Datetime {
+    /// julian date, days since 4714-11-24 12:00:00 BC (proleptic gregorian) with the time of day as a fraction,
+    /// an f64 resolves about 40 microseconds at the present day. Infinite for INFINITY and NEG_INFINITY
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// let dt = Datetime::from_rfc3339("2000-01-01 12:00:00").unwrap();
+    /// assert_eq!(dt.to_julian_day(), 2451545.0);
+    /// ```
+    pub fn to_julian_day(&self) -> f64 {
+        if self.is_infinite() {
+            return self.infinity_f64();
+        }
+        self.julian_day_number() as f64 - 0.5 + self.day_fraction()
+    }
+
+    /// create from a julian date, rounded to microseconds
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// assert_eq!(
+    ///     Datetime::from_julian_day(0.0).to_string(),
+    ///     "4714-11-24 12:00:00 BC"
+    /// );
+    /// ```
+    pub fn from_julian_day(jd: f64) -> Self {
+        Self::from_day_fraction(JDN_UNIX, jd + 0.5)
+    }
+
+    /// julian day number of the date, the time of day is ignored.
+    /// i64::MAX for INFINITY and i64::MIN for NEG_INFINITY like the other day numbers
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// let dt = Datetime::from_str("4714-11-24 00:00:00 BC").unwrap();
+    /// assert_eq!(dt.julian_day_number(), 0);
+    /// assert_eq!(Datetime::from_julian_day_number(0), dt);
+    /// ```
+    pub fn julian_day_number(&self) -> i64 {
+        offset_count(self.unix_days(), JDN_UNIX)
+    }
+
+    /// midnight of the date with the given julian day number
+    pub fn from_julian_day_number(jdn: i64) -> Self {
+        Self::from_unix_days(jdn - JDN_UNIX)
+    }
+
+    /// modified julian date, days since 1858-11-17 00:00:00 with the time of day as a fraction
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// let dt = Datetime::from_rfc3339("1858-11-17 06:00:00").unwrap();
+    /// assert_eq!(dt.to_modified_julian_day(), 0.25);
+    /// assert_eq!(Datetime::from_modified_julian_day(0.25), dt);
+    /// ```
+    pub fn to_modified_julian_day(&self) -> f64 {
+        if self.is_infinite() {
+            return self.infinity_f64();
+        }
+        self.modified_julian_day_number() as f64 + self.day_fraction()
+    }
+
+    /// create from a modified julian date, rounded to microseconds
+    pub fn from_modified_julian_day(mjd: f64) -> Self {
+        Self::from_day_fraction(MJD_UNIX, mjd)
+    }
+
+    /// modified julian day number of the date, the time of day is ignored
+    pub fn modified_julian_day_number(&self) -> i64 {
+        offset_count(self.unix_days(), MJD_UNIX)
+    }
+
+    /// midnight of the date with the given modified julian day number
+    pub fn from_modified_julian_day_number(mjd: i64) -> Self {
+        Self::from_unix_days(mjd - MJD_UNIX)
+    }
+
+    /// rata die, 0001-01-01 is day 1, the time of day is ignored
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// let dt = Datetime::from_str("0001-01-01 00:00:00").unwrap();
+    /// assert_eq!(dt.rata_die(), 1);
+    /// assert_eq!(Datetime::from_rata_die(0).to_string(), "0001-12-31 00:00:00 BC");
+    /// ```
+    pub fn rata_die(&self) -> i64 {
+        offset_count(self.unix_days(), RD_UNIX)
+    }
+
+    /// midnight of the date with the given rata die
+    pub fn from_rata_die(rd: i64) -> Self {
+        Self::from_unix_days(rd - RD_UNIX)
+    }
+
+    /// time of day as a fraction of a day
+    fn day_fraction(&self) -> f64 {
+        let nanos = ((self.hour() * 60 + self.minute()) * 60 + self.second()) * 1_000_000_000
+            + self.nanosecond();
+        nanos as f64 / (MICROS_PER_DAY * 1000.0)
+    }
+
+    /// the Datetime `days` after midnight of the day numbered `origin`
+    fn from_day_fraction(origin: i64, days: f64) -> Self {
+        let whole = days.floor();
+        let micros = ((days - whole) * MICROS_PER_DAY).round() as i64;
+        let mut dt = Self::from_unix_days(whole as i64 - origin);
+        dt.add_nanoseconds(micros * 1000);
+        dt
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{days_from_civil, Datetime};
+
+/// (year, month, TAI - UTC) for every leap second announced by the IERS,
+/// each offset takes effect at 00:00:00 UTC on the first day of the month
+const LEAP_SECONDS: [(i64, i64, i64); 28] = [
+    (1972, 1, 10),
+    (1972, 7, 11),
+    (1973, 1, 12),
+    (1974, 1, 13),
+    (1975, 1, 14),
+    (1976, 1, 15),
+    (1977, 1, 16),
+    (1978, 1, 17),
+    (1979, 1, 18),
+    (1980, 1, 19),
+    (1981, 7, 20),
+    (1982, 7, 21),
+    (1983, 7, 22),
+    (1985, 7, 23),
+    (1988, 1, 24),
+    (1990, 1, 25),
+    (1991, 1, 26),
+    (1992, 7, 27),
+    (1993, 7, 28),
+    (1994, 7, 29),
+    (1996, 1, 30),
+    (1997, 7, 31),
+    (1999, 1, 32),
+    (2006, 1, 33),
+    (2009, 1, 34),
+    (2012, 7, 35),
+    (2015, 7, 36),
+    (2017, 1, 37),
+];
+
+/// unix seconds at which each TAI - UTC offset of the leap second table starts
+pub(crate) fn leap_second_table() -> impl Iterator<Item = (i64, i64)> {
+    LEAP_SECONDS
+        .iter()
+        .map(|&(year, month, offset)| (days_from_civil(year, month, 1) * 86400, offset))
+}
+
+impl Datetime {
+    /// the number of seconds TAI is ahead of UTC at this instant,
+    /// instants before 1972 use the initial 10 seconds
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// assert_eq!(Datetime::from_rfc3339("2016-12-31 23:59:59").unwrap().tai_offset(), 36);
+    /// assert_eq!(Datetime::from_rfc3339("2017-01-01 00:00:00").unwrap().tai_offset(), 37);
+    /// ```
+    pub fn tai_offset(&self) -> i64 {
+        let s = self.unix_seconds();
+        leap_second_table()
+            .take_while(|&(start, _)| start <= s)
+            .last()
+            .map_or(10, |(_, offset)| offset)
+    }
+}
@@ -6,9 +6,63 @@ use std::{
 use regex::Regex;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+mod astro;
+mod chinese;
+mod epoch;
+mod era;
+mod excel;
+mod gps;
+mod hebrew;
+mod hijri;
+mod julian;
+mod julian_day;
+mod leap;
+mod moon;
+#[cfg(feature = "mysql")]
+mod mysql;
+mod ntp;
+mod offset;
+mod persian;
+#[cfg(feature = "postgres")]
+mod postgres;
+mod range;
+mod sntp;
+mod solar_term;
+mod span;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+mod sun;
+mod time_of_day;
+
+pub use chinese::ChineseDate;
+pub use epoch::{Epoch, EpochUnit};
+pub use era::{EraDatetime, EraSystem};
+pub use excel::DateSystem;
+pub use gps::GPS_WEEK_ROLLOVER;
+pub use hebrew::{HebrewDate, HebrewHoliday};
+pub use hijri::{HijriCalendar, HijriDate};
+pub use julian::{Cutover, JulianDate};
+pub use moon::MoonPhase;
+#[cfg(feature = "mysql")]
+pub use mysql::{MySqlDate, MySqlNonZero, MySqlOption, MySqlUtc};
+pub use ntp::NtpShort;
+pub use offset::OffsetDatetime;
+pub use persian::PersianDate;
+#[cfg(feature = "postgres")]
+pub use postgres::{PgDate, PgText};
+pub use range::DatetimeRange;
+pub use sntp::{SntpClient, SntpReply};
+pub use solar_term::SolarTerm;
+pub use span::Span;
+#[cfg(feature = "sqlite")]
+pub use sqlite::{SqliteJulianDay, SqliteUnix};
+pub use sun::{SunEvents, Twilight};
+pub use time_of_day::{OffsetTime, TimeOfDay};
+
 /// Datetime
 /// # Example
 /// ```no_run
+/// # use sys_datetime::Datetime;
 /// let mut dt = Datetime::default();
 /// dt.add_years(1970).add_months(1).add_days(1);
 /// dt.add_seconds(Datetime::timestamp().as_secs() as i64);
@@ -25,11 +79,60 @@ pub struct Datetime {
     hour: u8,
     minute: u8,
     second: u8,
+    nanosecond: u32,
 }
 
 impl Datetime {
+    /// later than every other Datetime, postgres 'infinity'. No method panics on INFINITY or
+    /// NEG_INFINITY: the add methods, round_subsec and the constructors relative to a reference
+    /// leave them unchanged, counts saturate to i64::MAX and i64::MIN, julian days and other
+    /// real numbers are infinite, angles and fractions are NaN, names are empty, and the other
+    /// conversions such as calendar dates, solar terms, moon phases and sun events give none
+    /// ```
+    /// # use sys_datetime::{Datetime, Epoch, Span};
+    /// let mut dt = Datetime::INFINITY;
+    /// dt.add_days(1).add_seconds(-1).add_span(&Span::new(1, 0, 0)).round_subsec(3);
+    /// assert_eq!(dt, Datetime::INFINITY);
+    /// let now = Datetime::from_str("2024-05-06 07:08:09").unwrap();
+    /// assert_eq!(dt.seconds_since(now), i64::MAX);
+    /// assert_eq!(Datetime::NEG_INFINITY.seconds_since(now), i64::MIN);
+    /// assert_eq!(dt.julian_day_number(), i64::MAX);
+    /// assert_eq!(Datetime::NEG_INFINITY.gps_seconds(), i64::MIN);
+    /// assert_eq!(dt.to_julian_day(), f64::INFINITY);
+    /// assert_eq!(Datetime::NEG_INFINITY.to_julian_day(), f64::NEG_INFINITY);
+    /// assert_eq!(Epoch::UNIX.count(&dt), None);
+    /// assert_eq!(dt.to_excel_serial(sys_datetime::DateSystem::Excel1900), None);
+    /// assert_eq!(sys_datetime::JulianDate::from_datetime(&dt), None);
+    /// assert_eq!(dt.solar_term(), None);
+    /// assert_eq!(dt.day_of_week(), "");
+    /// ```
+    pub const INFINITY: Datetime = Datetime {
+        year: i64::MAX,
+        month: 12,
+        day: 31,
+        hour: 23,
+        minute: 59,
+        second: 59,
+        nanosecond: 999_999_999,
+    };
+
+    /// earlier than every other Datetime, postgres '-infinity'
+    pub const NEG_INFINITY: Datetime = Datetime {
+        year: i64::MIN,
+        month: 1,
+        day: 1,
+        hour: 0,
+        minute: 0,
+        second: 0,
+        nanosecond: 0,
+    };
+
     /// plus years
     pub fn add_years(&mut self, years: i64) -> &mut Self {
+        if self.is_infinite() {
+            return self;
+        }
+
         if self.year < 0 {
             self.year += years;
             if self.year >= 0 {
@@ -63,6 +166,10 @@ impl Datetime {
 
     /// plus months
     pub fn add_months(&mut self, months: i64) -> &mut Self {
+        if self.is_infinite() {
+            return self;
+        }
+
         let ys = (self.month as i64 + months) / 12;
         let mut ms = (self.month as i64 + months) % 12;
 
@@ -89,6 +196,10 @@ impl Datetime {
 
     /// plus days
     pub fn add_days(&mut self, days: i64) -> &mut Self {
+        if self.is_infinite() {
+            return self;
+        }
+
         let mut ds = self.day as i64 + days;
 
         self.day = 1;
@@ -224,11 +335,16 @@ impl Datetime {
 
     /// plus hours
     /// ```no_run
+    /// # use sys_datetime::Datetime;
     /// let mut dt = Datetime::now();
     /// dt.add_hours(8);
     /// println!("{}", dt);
     /// ```
     pub fn add_hours(&mut self, hours: i64) -> &mut Self {
+        if self.is_infinite() {
+            return self;
+        }
+
         let mut hs = (self.hour as i64 + hours) % 24;
 
         if hs < 0 {
@@ -246,6 +362,10 @@ impl Datetime {
 
     /// plus minutes
     pub fn add_minutes(&mut self, minutes: i64) -> &mut Self {
+        if self.is_infinite() {
+            return self;
+        }
+
         let mut ms = (self.minute as i64 + minutes) % 60;
 
         if ms < 0 {
@@ -263,6 +383,10 @@ impl Datetime {
 
     /// plus seconds
     pub fn add_seconds(&mut self, seconds: i64) -> &mut Self {
+        if self.is_infinite() {
+            return self;
+        }
+
         let mut ss = (self.second as i64 + seconds) % 60;
 
         if ss < 0 {
@@ -278,6 +402,47 @@ impl Datetime {
         self
     }
 
+    /// plus nanoseconds
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// let mut dt = Datetime::from_str("2000-01-01 00:00:00").unwrap();
+    /// dt.add_nanoseconds(-500_000_000);
+    /// assert_eq!(dt.to_string(), "1999-12-31 23:59:59.5");
+    /// assert_eq!(Datetime::from_str("1999-12-31 23:59:59.5"), Some(dt));
+    /// ```
+    pub fn add_nanoseconds(&mut self, nanoseconds: i64) -> &mut Self {
+        if self.is_infinite() {
+            return self;
+        }
+
+        let ns = self.nanosecond as i64 + nanoseconds;
+
+        self.add_seconds(ns.div_euclid(1_000_000_000));
+
+        self.nanosecond = ns.rem_euclid(1_000_000_000) as u32;
+
+        self
+    }
+
+    /// round the fraction of a second to `digits` decimal places, halves are rounded up
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// let mut dt = Datetime::from_str("1999-12-31 23:59:59.9996").unwrap();
+    /// dt.round_subsec(3);
+    /// assert_eq!(dt.to_string(), "2000-01-01 00:00:00");
+    /// ```
+    pub fn round_subsec(&mut self, digits: u32) -> &mut Self {
+        if digits < 9 && !self.is_infinite() {
+            let unit = 10u32.pow(9 - digits);
+            let ns = (self.nanosecond + unit / 2) / unit * unit;
+
+            self.nanosecond = 0;
+            self.add_nanoseconds(ns as i64);
+        }
+
+        self
+    }
+
     #[inline(always)]
     pub fn year(&self) -> i64 {
         self.year
@@ -308,14 +473,25 @@ impl Datetime {
         self.second as i64
     }
 
-    /// may be used to obtain the day of the week for dates on or after 0000-03-01
+    #[inline(always)]
+    pub fn nanosecond(&self) -> i64 {
+        self.nanosecond as i64
+    }
+
+    /// may be used to obtain the day of the week for dates on or after 0000-03-01,
+    /// empty for INFINITY and NEG_INFINITY
     /// ```no_run
+    /// # use sys_datetime::Datetime;
     /// assert_eq!(
     ///     Datetime::from_rfc3339("1970-01-01").unwrap().day_of_week(),
     ///     "Thursday"
     /// );
     /// ```
     pub fn day_of_week(&self) -> &'static str {
+        if self.is_infinite() {
+            return "";
+        }
+
         let mut year = self.year();
         let mut month = self.month();
         let day = self.day();
@@ -345,14 +521,25 @@ impl Datetime {
             [((26 * month - 2) / 10 + day + year + year / 4 + cent / 4 + 5 * cent) as usize % 7]
     }
 
-    /// the number of seconds between two Datetime
+    /// the number of seconds between two Datetime, i64::MAX or i64::MIN when one is infinite
     /// ```no_run
+    /// # use sys_datetime::Datetime;
     /// assert_eq!(
     ///     Datetime::now().seconds_since(Datetime::from_rfc3339("1970-01-01").unwrap()),
     ///     Datetime::timestamp().as_secs() as i64
     /// );
     /// ```
     pub fn seconds_since(&self, earlier: Datetime) -> i64 {
+        if self.is_infinite() || earlier.is_infinite() {
+            return if *self == earlier {
+                0
+            } else if *self > earlier {
+                i64::MAX
+            } else {
+                i64::MIN
+            };
+        }
+
         let stop = Self {
             year: self.year,
             month: self.month,
@@ -360,6 +547,7 @@ impl Datetime {
             hour: 0,
             minute: 0,
             second: 0,
+            nanosecond: 0,
         };
         let mut start = Self {
             year: earlier.year,
@@ -368,6 +556,7 @@ impl Datetime {
             hour: 0,
             minute: 0,
             second: 0,
+            nanosecond: 0,
         };
 
         let mut ss =
@@ -391,8 +580,30 @@ impl Datetime {
         ss
     }
 
+    /// is the value one of the sentinels INFINITY and NEG_INFINITY
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// assert!(Datetime::INFINITY.is_infinite());
+    /// assert_eq!(Datetime::NEG_INFINITY.to_string(), "-infinity");
+    /// assert_eq!(Datetime::from_str("infinity"), Some(Datetime::INFINITY));
+    /// ```
+    pub fn is_infinite(&self) -> bool {
+        *self == Self::INFINITY || *self == Self::NEG_INFINITY
+    }
+
+    /// f64::INFINITY for INFINITY and f64::NEG_INFINITY for NEG_INFINITY,
+    /// the value of a conversion to a real number
+    pub(crate) fn infinity_f64(&self) -> f64 {
+        if *self == Self::INFINITY {
+            f64::INFINITY
+        } else {
+            f64::NEG_INFINITY
+        }
+    }
+
     /// is the value valid
     /// ```no_run
+    /// # use sys_datetime::Datetime;
     /// let mut dt = Datetime::default();
     /// assert!(!dt.is_valid());
     /// dt.add_seconds(0);
@@ -403,10 +614,8 @@ impl Datetime {
             return false;
         }
         match self.month {
-            1 | 3 | 5 | 7 | 8 | 10 | 12 => {
-                if self.day > 31 {
-                    return false;
-                }
+            1 | 3 | 5 | 7 | 8 | 10 | 12 if self.day > 31 => {
+                return false;
             }
             2 => {
                 let yz = if self.year < 0 {
@@ -424,25 +633,34 @@ impl Datetime {
                     }
                 }
             }
-            4 | 6 | 9 | 11 => {
-                if self.day > 30 {
-                    return false;
-                }
+            4 | 6 | 9 | 11 if self.day > 30 => {
+                return false;
             }
             _ => {}
         }
-        if self.hour >= 24 || self.minute >= 60 || self.second >= 60 {
+        if self.hour >= 24
+            || self.minute >= 60
+            || self.second >= 60
+            || self.nanosecond >= 1_000_000_000
+        {
             return false;
         }
         true
     }
 
     /// create from string
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(dt: &str) -> Option<Self> {
-        if let Ok(re) = Regex::new("(\\d+)\\D+(\\d+)\\D+(\\d+)\\D*(\\d*)\\D*(\\d*)\\D*(\\d*)(\\D*)")
-        {
+        match dt.trim() {
+            "infinity" | "+infinity" => return Some(Self::INFINITY),
+            "-infinity" => return Some(Self::NEG_INFINITY),
+            _ => {}
+        }
+        if let Ok(re) = Regex::new(
+            "(\\d+)\\D+(\\d+)\\D+(\\d+)\\D*(\\d*)\\D*(\\d*)\\D*(\\d*)(?:\\.(\\d+))?(\\D*)",
+        ) {
             if let Some(caps) = re.captures(dt) {
-                let year = if matches!(caps.get(7),Some(b) if b.as_str().contains("BC")) {
+                let year = if matches!(caps.get(8),Some(b) if b.as_str().contains("BC")) {
                     -caps
                         .get(1)
                         .map_or(0, |m| m.as_str().parse().unwrap_or_default())
@@ -465,6 +683,7 @@ impl Datetime {
                 let second = caps
                     .get(6)
                     .map_or(0, |m| m.as_str().parse().unwrap_or_default());
+                let nanosecond = caps.get(7).map_or(0, |m| parse_fraction(m.as_str()));
 
                 return Some(Self {
                     year,
@@ -473,6 +692,7 @@ impl Datetime {
                     hour,
                     minute,
                     second,
+                    nanosecond,
                 });
             }
         }
@@ -482,6 +702,7 @@ impl Datetime {
 
     /// create from rfc3339 string
     /// ```no_run
+    /// # use sys_datetime::Datetime;
     /// assert_eq!(
     ///     Datetime::from_rfc3339("2020-01-01 08:00:00+08:00")
     ///         .unwrap()
@@ -498,6 +719,7 @@ impl Datetime {
                 hour: 0,
                 minute: 0,
                 second: 0,
+                nanosecond: 0,
             };
 
             if rfc.len() >= 19 {
@@ -506,10 +728,17 @@ impl Datetime {
                 dt.second = rfc[17..19].parse().ok()?;
 
                 if rfc.len() > 19 {
-                    let tail = &rfc[19..];
-                    if let Some(p) = tail.find(&['+', '-']) {
+                    let mut tail = &rfc[19..];
+                    if let Some(fraction) = tail.strip_prefix('.') {
+                        let p = fraction
+                            .find(|c: char| !c.is_ascii_digit())
+                            .unwrap_or(fraction.len());
+                        dt.nanosecond = parse_fraction(&fraction[..p]);
+                        tail = &fraction[p..];
+                    }
+                    if let Some(p) = tail.find(['+', '-']) {
                         let z: Vec<&str> = tail[p + 1..].split(':').collect();
-                        if z.len() > 0 {
+                        if !z.is_empty() {
                             if &tail[p..p + 1] == "+" {
                                 dt.add_hours(-z[0].parse().ok()?);
                                 if z.len() > 1 {
@@ -538,36 +767,144 @@ impl Datetime {
             .unwrap_or_default()
     }
 
-    /// current time
+    /// current time with the nanoseconds of the system clock
     pub fn now() -> Self {
-        let mut epoch = Self {
-            year: 1970,
-            month: 1,
-            day: 1,
+        let timestamp = Datetime::timestamp();
+        let mut dt = Self::from_unix_seconds(timestamp.as_secs() as i64);
+        dt.nanosecond = timestamp.subsec_nanos();
+        dt
+    }
+
+    /// midnight of a date, a negative year is BC
+    pub(crate) const fn from_ymd(year: i64, month: u8, day: u8) -> Self {
+        Self {
+            year,
+            month,
+            day,
             hour: 0,
             minute: 0,
             second: 0,
+            nanosecond: 0,
+        }
+    }
+
+    /// days since 1970-01-01, the time of day is ignored,
+    /// i64::MAX for INFINITY and i64::MIN for NEG_INFINITY
+    pub(crate) fn unix_days(&self) -> i64 {
+        if *self == Self::INFINITY {
+            return i64::MAX;
+        }
+        if *self == Self::NEG_INFINITY {
+            return i64::MIN;
+        }
+        let yz = if self.year < 0 {
+            self.year + 1
+        } else {
+            self.year
         };
-        epoch.add_seconds(Datetime::timestamp().as_secs() as i64);
-        epoch
+        days_from_civil(yz, self.month as i64, self.day as i64)
     }
+
+    /// midnight of the day that is `days` after 1970-01-01
+    pub(crate) fn from_unix_days(days: i64) -> Self {
+        let (yz, month, day) = civil_from_days(days);
+        Self {
+            year: if yz <= 0 { yz - 1 } else { yz },
+            month: month as u8,
+            day: day as u8,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            nanosecond: 0,
+        }
+    }
+
+    /// seconds since 1970-01-01 00:00:00, saturated for INFINITY and NEG_INFINITY
+    pub(crate) fn unix_seconds(&self) -> i64 {
+        self.unix_days()
+            .saturating_mul(86400)
+            .saturating_add(self.hour() * 3600 + self.minute() * 60 + self.second())
+    }
+
+    /// the Datetime that is `seconds` after 1970-01-01 00:00:00
+    pub(crate) fn from_unix_seconds(seconds: i64) -> Self {
+        let mut dt = Self::from_unix_days(seconds.div_euclid(86400));
+        let s = seconds.rem_euclid(86400);
+        dt.hour = (s / 3600) as u8;
+        dt.minute = (s / 60 % 60) as u8;
+        dt.second = (s % 60) as u8;
+        dt
+    }
+}
+
+/// `count` moved by `offset`, unless it is the i64::MAX or i64::MIN an infinite value
+/// saturates to
+pub(crate) fn offset_count(count: i64, offset: i64) -> i64 {
+    match count {
+        i64::MIN | i64::MAX => count,
+        _ => count + offset,
+    }
+}
+
+/// nanoseconds of the digits after a decimal point, digits past the ninth are dropped
+pub(crate) fn parse_fraction(digits: &str) -> u32 {
+    digits
+        .bytes()
+        .chain(std::iter::repeat(b'0'))
+        .take(9)
+        .fold(0, |n, b| n * 10 + (b - b'0') as u32)
+}
+
+/// days from 1970-01-01 to a proleptic gregorian date whose year 0 is 1 BC
+pub(crate) fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// the proleptic gregorian (year, month, day) of a day count from 1970-01-01, year 0 is 1 BC
+pub(crate) fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
 }
 
 impl Display for Datetime {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.year >= 0 {
-            write!(
-                f,
-                "{:0>4}-{:0>2}-{:0>2} {:0>2}:{:0>2}:{:0>2}",
-                self.year, self.month, self.day, self.hour, self.minute, self.second
-            )
-        } else {
-            write!(
-                f,
-                "{:0>4}-{:0>2}-{:0>2} {:0>2}:{:0>2}:{:0>2} BC",
-                -self.year, self.month, self.day, self.hour, self.minute, self.second
-            )
+        if *self == Self::INFINITY {
+            return write!(f, "infinity");
+        }
+        if *self == Self::NEG_INFINITY {
+            return write!(f, "-infinity");
+        }
+        write!(
+            f,
+            "{:0>4}-{:0>2}-{:0>2} {:0>2}:{:0>2}:{:0>2}",
+            self.year.abs(),
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second
+        )?;
+        if self.nanosecond != 0 {
+            let fraction = format!("{:0>9}", self.nanosecond);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
         }
+        if self.year < 0 {
+            write!(f, " BC")?;
+        }
+        Ok(())
     }
 }
 
@@ -587,7 +924,7 @@ impl<'de> Deserialize<'de> for Datetime {
         D: Deserializer<'de>,
     {
         let s = String::deserialize(deserializer)?;
-        if s.len() == 0 {
+        if s.is_empty() {
             Ok(Datetime::default())
         } else {
             if let Some(r) = Datetime::from_rfc3339(&s) {
@@ -601,96 +938,10 @@ impl<'de> Deserialize<'de> for Datetime {
     }
 }
 
-#[cfg(feature = "postgres")]
-impl sqlx::Type<sqlx::Postgres> for Datetime {
-    fn type_info() -> sqlx::postgres::PgTypeInfo {
-        sqlx::postgres::PgTypeInfo::with_name("TIMESTAMP")
-    }
-
-    fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
-        matches!(
-            ty.to_string().as_str(),
-            "TIMESTAMP" | "TIMESTAMPTZ" | "DATE" | "VARCHAR" | "TEXT"
-        )
-    }
-}
-
-#[cfg(feature = "postgres")]
-impl sqlx::Encode<'_, sqlx::Postgres> for Datetime {
-    fn encode_by_ref(
-        &self,
-        buf: &mut sqlx::postgres::PgArgumentBuffer,
-    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
-        let s = self.seconds_since(Self {
-            year: 2000,
-            month: 1,
-            day: 1,
-            hour: 0,
-            minute: 0,
-            second: 0,
-        }) * 1000000;
-        sqlx::Encode::<sqlx::Postgres>::encode_by_ref(&s, buf)
-    }
-
-    fn size_hint(&self) -> usize {
-        8
-    }
-}
-
-#[cfg(feature = "postgres")]
-impl<'r> sqlx::Decode<'r, sqlx::Postgres> for Datetime
-where
-    i64: sqlx::Decode<'r, sqlx::Postgres>,
-    i32: sqlx::Decode<'r, sqlx::Postgres>,
-    &'r str: sqlx::Decode<'r, sqlx::Postgres>,
-{
-    /// when using TIMESTAMPTZ please pay attention to time zone conversion such as your_timestamp AT TIME ZONE 'your_timezone'
-    fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
-        match sqlx::ValueRef::type_info(&value)
-            .as_ref()
-            .to_string()
-            .as_str()
-        {
-            "TIMESTAMP" | "TIMESTAMPTZ" => {
-                let mut epoch = Self {
-                    year: 2000,
-                    month: 1,
-                    day: 1,
-                    hour: 0,
-                    minute: 0,
-                    second: 0,
-                };
-                epoch.add_seconds(i64::decode(value)? / 1000000);
-                Ok(epoch)
-            }
-            "DATE" => {
-                let mut epoch = Self {
-                    year: 2000,
-                    month: 1,
-                    day: 1,
-                    hour: 0,
-                    minute: 0,
-                    second: 0,
-                };
-                epoch.add_days(i32::decode(value)? as i64);
-                Ok(epoch)
-            }
-            _ => {
-                let s = <&str>::decode(value)?;
-                let res = if let Some(r) = Datetime::from_rfc3339(s) {
-                    r
-                } else if let Some(r) = Datetime::from_str(s) {
-                    r
-                } else {
-                    Datetime::default()
-                };
-                Ok(res)
-            }
-        }
-    }
-}
-
-#[cfg(all(feature = "sqlx", not(feature = "postgres")))]
+#[cfg(all(
+    feature = "sqlx",
+    not(any(feature = "postgres", feature = "mysql", feature = "sqlite"))
+))]
 impl<'r, DB: sqlx::Database> sqlx::Type<DB> for Datetime
 where
     DB: sqlx::Database,
@@ -708,7 +959,10 @@ where
     }
 }
 
-#[cfg(all(feature = "sqlx", not(feature = "postgres")))]
+#[cfg(all(
+    feature = "sqlx",
+    not(any(feature = "postgres", feature = "mysql", feature = "sqlite"))
+))]
 impl<'r, DB> sqlx::Encode<'r, DB> for Datetime
 where
     DB: sqlx::Database,
@@ -716,57 +970,27 @@ where
 {
     fn encode_by_ref(
         &self,
-        buf: &mut <DB as sqlx::Database>::ArgumentBuffer<'r>,
+        buf: &mut <DB as sqlx::Database>::ArgumentBuffer,
     ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
         <String>::encode(self.to_string(), buf)
     }
 }
 
-#[cfg(all(feature = "sqlx", not(feature = "postgres")))]
+#[cfg(all(
+    feature = "sqlx",
+    not(any(feature = "postgres", feature = "mysql", feature = "sqlite"))
+))]
 impl<'r, DB> sqlx::Decode<'r, DB> for Datetime
 where
     DB: sqlx::Database,
-    &'r [u8]: sqlx::Decode<'r, DB>,
     &'r str: sqlx::Decode<'r, DB>,
 {
     fn decode(
         value: <DB as sqlx::Database>::ValueRef<'r>,
     ) -> Result<Self, sqlx::error::BoxDynError> {
-        match sqlx::ValueRef::type_info(&value)
-            .as_ref()
-            .to_string()
-            .as_str()
-        {
-            #[cfg(feature = "mysql")]
-            "TIMESTAMP" | "DATETIME" | "DATE" => {
-                let buf = <&[u8]>::decode(value)?;
-                let len = buf[0];
-                let mut dt = Self {
-                    year: ((buf[2] as i64) << 8) + buf[1] as i64,
-                    month: buf[3],
-                    day: buf[4],
-                    hour: 0,
-                    minute: 0,
-                    second: 0,
-                };
-                if len > 4 {
-                    dt.hour = buf[5];
-                    dt.minute = buf[6];
-                    dt.second = buf[7];
-                }
-                Ok(dt)
-            }
-            _ => {
-                let s = <&str>::decode(value)?;
-                let res = if let Some(r) = Datetime::from_rfc3339(s) {
-                    r
-                } else if let Some(r) = Datetime::from_str(s) {
-                    r
-                } else {
-                    Datetime::default()
-                };
-                Ok(res)
-            }
-        }
+        let s = <&str>::decode(value)?;
+        Ok(Datetime::from_rfc3339(s)
+            .or_else(|| Datetime::from_str(s))
+            .unwrap_or_default())
     }
 }
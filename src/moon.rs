@@ -0,0 +1,316 @@
+use std::fmt::Display;
+
+use crate::{
+    astro::{centuries, decimal_year, from_jde, normalize, to_jde},
+    Datetime,
+};
+
+/// periodic terms of the new moon, (coefficient, power of E, multiples of M, M', F, Ω)
+const NEW_MOON: [(f64, i32, [f64; 4]); 25] = [
+    (-0.40720, 0, [0.0, 1.0, 0.0, 0.0]),
+    (0.17241, 1, [1.0, 0.0, 0.0, 0.0]),
+    (0.01608, 0, [0.0, 2.0, 0.0, 0.0]),
+    (0.01039, 0, [0.0, 0.0, 2.0, 0.0]),
+    (0.00739, 1, [-1.0, 1.0, 0.0, 0.0]),
+    (-0.00514, 1, [1.0, 1.0, 0.0, 0.0]),
+    (0.00208, 2, [2.0, 0.0, 0.0, 0.0]),
+    (-0.00111, 0, [0.0, 1.0, -2.0, 0.0]),
+    (-0.00057, 0, [0.0, 1.0, 2.0, 0.0]),
+    (0.00056, 1, [1.0, 2.0, 0.0, 0.0]),
+    (-0.00042, 0, [0.0, 3.0, 0.0, 0.0]),
+    (0.00042, 1, [1.0, 0.0, 2.0, 0.0]),
+    (0.00038, 1, [1.0, 0.0, -2.0, 0.0]),
+    (-0.00024, 1, [-1.0, 2.0, 0.0, 0.0]),
+    (-0.00017, 0, [0.0, 0.0, 0.0, 1.0]),
+    (-0.00007, 0, [2.0, 1.0, 0.0, 0.0]),
+    (0.00004, 0, [0.0, 2.0, -2.0, 0.0]),
+    (0.00004, 0, [3.0, 0.0, 0.0, 0.0]),
+    (0.00003, 0, [1.0, 1.0, -2.0, 0.0]),
+    (0.00003, 0, [0.0, 2.0, 2.0, 0.0]),
+    (-0.00003, 0, [1.0, 1.0, 2.0, 0.0]),
+    (0.00003, 0, [-1.0, 1.0, 2.0, 0.0]),
+    (-0.00002, 0, [-1.0, 1.0, -2.0, 0.0]),
+    (-0.00002, 0, [1.0, 3.0, 0.0, 0.0]),
+    (0.00002, 0, [0.0, 4.0, 0.0, 0.0]),
+];
+
+/// periodic terms of the full moon
+const FULL_MOON: [(f64, i32, [f64; 4]); 25] = [
+    (-0.40614, 0, [0.0, 1.0, 0.0, 0.0]),
+    (0.17302, 1, [1.0, 0.0, 0.0, 0.0]),
+    (0.01614, 0, [0.0, 2.0, 0.0, 0.0]),
+    (0.01043, 0, [0.0, 0.0, 2.0, 0.0]),
+    (0.00734, 1, [-1.0, 1.0, 0.0, 0.0]),
+    (-0.00515, 1, [1.0, 1.0, 0.0, 0.0]),
+    (0.00209, 2, [2.0, 0.0, 0.0, 0.0]),
+    (-0.00111, 0, [0.0, 1.0, -2.0, 0.0]),
+    (-0.00057, 0, [0.0, 1.0, 2.0, 0.0]),
+    (0.00056, 1, [1.0, 2.0, 0.0, 0.0]),
+    (-0.00042, 0, [0.0, 3.0, 0.0, 0.0]),
+    (0.00042, 1, [1.0, 0.0, 2.0, 0.0]),
+    (0.00038, 1, [1.0, 0.0, -2.0, 0.0]),
+    (-0.00024, 1, [-1.0, 2.0, 0.0, 0.0]),
+    (-0.00017, 0, [0.0, 0.0, 0.0, 1.0]),
+    (-0.00007, 0, [2.0, 1.0, 0.0, 0.0]),
+    (0.00004, 0, [0.0, 2.0, -2.0, 0.0]),
+    (0.00004, 0, [3.0, 0.0, 0.0, 0.0]),
+    (0.00003, 0, [1.0, 1.0, -2.0, 0.0]),
+    (0.00003, 0, [0.0, 2.0, 2.0, 0.0]),
+    (-0.00003, 0, [1.0, 1.0, 2.0, 0.0]),
+    (0.00003, 0, [-1.0, 1.0, 2.0, 0.0]),
+    (-0.00002, 0, [-1.0, 1.0, -2.0, 0.0]),
+    (-0.00002, 0, [1.0, 3.0, 0.0, 0.0]),
+    (0.00002, 0, [0.0, 4.0, 0.0, 0.0]),
+];
+
+/// periodic terms of the first and last quarters
+const QUARTER: [(f64, i32, [f64; 4]); 25] = [
+    (-0.62801, 0, [0.0, 1.0, 0.0, 0.0]),
+    (0.17172, 1, [1.0, 0.0, 0.0, 0.0]),
+    (-0.01183, 1, [1.0, 1.0, 0.0, 0.0]),
+    (0.00862, 0, [0.0, 2.0, 0.0, 0.0]),
+    (0.00804, 0, [0.0, 0.0, 2.0, 0.0]),
+    (0.00454, 1, [-1.0, 1.0, 0.0, 0.0]),
+    (0.00204, 2, [2.0, 0.0, 0.0, 0.0]),
+    (-0.00180, 0, [0.0, 1.0, -2.0, 0.0]),
+    (-0.00070, 0, [0.0, 1.0, 2.0, 0.0]),
+    (-0.00040, 0, [0.0, 3.0, 0.0, 0.0]),
+    (-0.00034, 1, [-1.0, 2.0, 0.0, 0.0]),
+    (0.00032, 1, [1.0, 0.0, 2.0, 0.0]),
+    (0.00032, 1, [1.0, 0.0, -2.0, 0.0]),
+    (-0.00028, 2, [2.0, 1.0, 0.0, 0.0]),
+    (0.00027, 1, [1.0, 2.0, 0.0, 0.0]),
+    (-0.00017, 0, [0.0, 0.0, 0.0, 1.0]),
+    (-0.00005, 0, [-1.0, 1.0, -2.0, 0.0]),
+    (0.00004, 0, [0.0, 2.0, 2.0, 0.0]),
+    (-0.00004, 0, [1.0, 1.0, 2.0, 0.0]),
+    (0.00004, 0, [-2.0, 1.0, 0.0, 0.0]),
+    (0.00003, 0, [1.0, 1.0, -2.0, 0.0]),
+    (0.00003, 0, [3.0, 0.0, 0.0, 0.0]),
+    (0.00002, 0, [0.0, 2.0, -2.0, 0.0]),
+    (0.00002, 0, [-1.0, 1.0, 2.0, 0.0]),
+    (-0.00002, 0, [1.0, 3.0, 0.0, 0.0]),
+];
+
+/// planetary arguments (A, B, C) of the additional corrections C sin(A + B k)
+const PLANETARY: [(f64, f64, f64); 14] = [
+    (299.77, 0.107408, 0.000325),
+    (251.88, 0.016321, 0.000165),
+    (251.83, 26.651886, 0.000164),
+    (349.42, 36.412478, 0.000126),
+    (84.66, 18.206239, 0.000110),
+    (141.74, 53.303771, 0.000062),
+    (207.14, 2.453732, 0.000060),
+    (154.84, 7.306860, 0.000056),
+    (34.52, 27.261239, 0.000047),
+    (207.19, 0.121824, 0.000042),
+    (291.34, 1.844379, 0.000040),
+    (161.72, 24.198154, 0.000037),
+    (239.56, 25.513099, 0.000035),
+    (331.55, 3.592518, 0.000023),
+];
+
+/// the phase of the moon by its elongation from the sun, each covers an eighth of the cycle
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MoonPhase {
+    NewMoon,
+    WaxingCrescent,
+    FirstQuarter,
+    WaxingGibbous,
+    FullMoon,
+    WaningGibbous,
+    LastQuarter,
+    WaningCrescent,
+}
+
+impl MoonPhase {
+    pub const ALL: [MoonPhase; 8] = [
+        MoonPhase::NewMoon,
+        MoonPhase::WaxingCrescent,
+        MoonPhase::FirstQuarter,
+        MoonPhase::WaxingGibbous,
+        MoonPhase::FullMoon,
+        MoonPhase::WaningGibbous,
+        MoonPhase::LastQuarter,
+        MoonPhase::WaningCrescent,
+    ];
+
+    /// the english name such as "Waxing Crescent"
+    pub fn name(&self) -> &'static str {
+        match self {
+            MoonPhase::NewMoon => "New Moon",
+            MoonPhase::WaxingCrescent => "Waxing Crescent",
+            MoonPhase::FirstQuarter => "First Quarter",
+            MoonPhase::WaxingGibbous => "Waxing Gibbous",
+            MoonPhase::FullMoon => "Full Moon",
+            MoonPhase::WaningGibbous => "Waning Gibbous",
+            MoonPhase::LastQuarter => "Last Quarter",
+            MoonPhase::WaningCrescent => "Waning Crescent",
+        }
+    }
+
+    /// the utc instant of the new moon, first quarter, full moon or last quarter of lunation `k`,
+    /// lunation 0 is the new moon of 2000-01-06, accurate to about a minute.
+    /// None for the phases between the principal ones
+    /// ```
+    /// # use sys_datetime::MoonPhase;
+    /// let dt = MoonPhase::NewMoon.instant(300).unwrap();
+    /// assert_eq!(dt.to_string(), "2024-04-08 18:20:48");
+    /// assert_eq!(MoonPhase::WaxingCrescent.instant(300), None);
+    /// ```
+    pub fn instant(&self, k: i64) -> Option<Datetime> {
+        let fraction = match self {
+            MoonPhase::NewMoon => 0.0,
+            MoonPhase::FirstQuarter => 0.25,
+            MoonPhase::FullMoon => 0.5,
+            MoonPhase::LastQuarter => 0.75,
+            _ => return None,
+        };
+        Some(from_jde(phase_jde(k as f64 + fraction)))
+    }
+}
+
+impl Display for MoonPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Datetime {
+    /// the fraction of the disk of the moon lit by the sun, 0 at new moon and 1 at full moon,
+    /// NaN for INFINITY and NEG_INFINITY
+    /// ```
+    /// # use sys_datetime::{Datetime, MoonPhase};
+    /// let dt = Datetime::from_str("2024-04-23 23:49:00").unwrap();
+    /// assert!(dt.moon_illumination() > 0.99);
+    /// assert_eq!(dt.moon_phase(), Some(MoonPhase::FullMoon));
+    ///
+    /// let dt = Datetime::from_str("2024-04-12 00:00:00").unwrap();
+    /// assert_eq!(dt.moon_phase(), Some(MoonPhase::WaxingCrescent));
+    ///
+    /// assert!(Datetime::INFINITY.moon_illumination().is_nan());
+    /// assert_eq!(Datetime::INFINITY.moon_phase(), None);
+    /// ```
+    pub fn moon_illumination(&self) -> f64 {
+        (1.0 - moon_elongation(to_jde(self)).to_radians().cos()) / 2.0
+    }
+
+    /// the phase of the moon at this instant, none for INFINITY and NEG_INFINITY
+    pub fn moon_phase(&self) -> Option<MoonPhase> {
+        if self.is_infinite() {
+            return None;
+        }
+        let octant = (moon_elongation(to_jde(self)) / 45.0).round() as usize;
+        Some(MoonPhase::ALL[octant % 8])
+    }
+
+    /// the new moons, first quarters, full moons and last quarters from `start` until
+    /// before `end` in order, none when either is INFINITY or NEG_INFINITY
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// let start = Datetime::from_str("2024-04-01 00:00:00").unwrap();
+    /// let end = Datetime::from_str("2024-05-01 00:00:00").unwrap();
+    /// let phases: Vec<String> = Datetime::moon_phases(&start, &end)
+    ///     .iter()
+    ///     .map(|(phase, dt)| format!("{} {}", phase, dt))
+    ///     .collect();
+    /// assert_eq!(
+    ///     phases,
+    ///     [
+    ///         "Last Quarter 2024-04-02 03:14:42",
+    ///         "New Moon 2024-04-08 18:20:48",
+    ///         "First Quarter 2024-04-15 19:13:03",
+    ///         "Full Moon 2024-04-23 23:48:59"
+    ///     ]
+    /// );
+    /// ```
+    pub fn moon_phases(start: &Datetime, end: &Datetime) -> Vec<(MoonPhase, Datetime)> {
+        if start.is_infinite() || end.is_infinite() {
+            return vec![];
+        }
+        let (from, to) = (to_jde(start), to_jde(end));
+        let mut quarter = ((decimal_year(from) - 2000.0) * 12.3685 * 4.0).floor() as i64 - 4;
+        let mut phases = vec![];
+        loop {
+            let jde = phase_jde(quarter as f64 / 4.0);
+            if jde >= to {
+                return phases;
+            }
+            if jde >= from {
+                let phase = MoonPhase::ALL[quarter.rem_euclid(4) as usize * 2];
+                phases.push((phase, from_jde(jde)));
+            }
+            quarter += 1;
+        }
+    }
+}
+
+/// julian ephemeris day of the phase of lunation `k`, a multiple of 0.25 (Meeus chapter 49)
+fn phase_jde(k: f64) -> f64 {
+    let t = k / 1236.85;
+    let jde = 2451550.09766 + 29.530588861 * k + 0.00015437 * t * t - 0.000000150 * t * t * t
+        + 0.00000000073 * t * t * t * t;
+    let e = 1.0 - 0.002516 * t - 0.0000074 * t * t;
+    let m = (2.5534 + 29.10535670 * k - 0.0000014 * t * t - 0.00000011 * t * t * t).to_radians();
+    let mp = (201.5643 + 385.81693528 * k + 0.0107582 * t * t + 0.00001238 * t * t * t
+        - 0.000000058 * t * t * t * t)
+        .to_radians();
+    let f = (160.7108 + 390.67050284 * k - 0.0016118 * t * t - 0.00000227 * t * t * t
+        + 0.000000011 * t * t * t * t)
+        .to_radians();
+    let omega =
+        (124.7746 - 1.56375588 * k + 0.0020672 * t * t + 0.00000215 * t * t * t).to_radians();
+
+    let fraction = k - k.floor();
+    let quarter = fraction == 0.25 || fraction == 0.75;
+    let terms = if quarter {
+        &QUARTER
+    } else if fraction == 0.5 {
+        &FULL_MOON
+    } else {
+        &NEW_MOON
+    };
+    let mut correction: f64 = terms
+        .iter()
+        .map(|&(c, power, [a, b, g, o])| {
+            c * e.powi(power) * (a * m + b * mp + g * f + o * omega).sin()
+        })
+        .sum();
+    if quarter {
+        let w = 0.00306 - 0.00038 * e * m.cos() + 0.00026 * mp.cos() - 0.00002 * (mp - m).cos()
+            + 0.00002 * (mp + m).cos()
+            + 0.00002 * (2.0 * f).cos();
+        correction += if fraction == 0.25 { w } else { -w };
+    }
+
+    let planetary: f64 = PLANETARY
+        .iter()
+        .enumerate()
+        .map(|(i, &(a, b, c))| {
+            let mut angle = a + b * k;
+            if i == 0 {
+                angle -= 0.009173 * t * t;
+            }
+            c * angle.to_radians().sin()
+        })
+        .sum();
+    jde + correction + planetary
+}
+
+/// elongation of the moon from the sun in degrees, 0 at new moon and 180 at full moon,
+/// from the main terms of the mean elongation and anomalies (Meeus chapter 48)
+fn moon_elongation(jde: f64) -> f64 {
+    let t = centuries(jde);
+    let d =
+        (297.8501921 + 445267.1114034 * t - 0.0018819 * t * t + t * t * t / 545868.0).to_radians();
+    let m = (357.5291092 + 35999.0502909 * t - 0.0001536 * t * t).to_radians();
+    let mp =
+        (134.9633964 + 477198.8675055 * t + 0.0087414 * t * t + t * t * t / 69699.0).to_radians();
+    normalize(
+        d.to_degrees() + 6.289 * mp.sin() - 2.100 * m.sin()
+            + 1.274 * (2.0 * d - mp).sin()
+            + 0.658 * (2.0 * d).sin()
+            + 0.214 * (2.0 * mp).sin()
+            + 0.110 * d.sin(),
+    )
+}
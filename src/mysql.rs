@@ -0,0 +1,504 @@
+use sqlx::{
+    encode::IsNull,
+    error::{BoxDynError, UnexpectedNullError},
+    mysql::types::MySqlTime,
+    mysql::{MySqlTypeInfo, MySqlValueRef},
+    types::time::{Date, OffsetDateTime, PrimitiveDateTime},
+    Decode, Encode, MySql, Type, ValueRef,
+};
+
+use crate::{Datetime, Span};
+
+/// a DATETIME parameter. sqlx has no public constructor of a DATETIME, TIMESTAMP or DATE
+/// MySqlTypeInfo, so the `mysql` feature enables sqlx's `time` feature and borrows the type info
+/// of its time types, no value of the time crate is ever made
+impl Type<MySql> for Datetime {
+    fn type_info() -> MySqlTypeInfo {
+        <PrimitiveDateTime as Type<MySql>>::type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        matches!(
+            ty.to_string().as_str(),
+            "TIMESTAMP" | "DATETIME" | "DATE" | "VARCHAR" | "CHAR" | "TEXT"
+        )
+    }
+}
+
+/// the binary DATETIME of the mysql protocol, the fraction is rounded to microseconds.
+/// MySQL casts the parameter for TIMESTAMP and DATE columns, bind [`MySqlDate`] where the
+/// parameter itself must be a DATE. INFINITY, NEG_INFINITY and a year before 0 or after 9999
+/// are an error
+/// ```
+/// # use sqlx::{Encode, MySql};
+/// # use sys_datetime::Datetime;
+/// let mut buf = Vec::new();
+/// let dt = Datetime::from_str("2024-05-06 07:08:09.5").unwrap();
+/// assert!(Encode::<MySql>::encode_by_ref(&dt, &mut buf).is_ok());
+/// assert_eq!(buf, [11, 232, 7, 5, 6, 7, 8, 9, 32, 161, 7, 0]);
+///
+/// for dt in [Datetime::INFINITY, Datetime::NEG_INFINITY] {
+///     let e = Encode::<MySql>::encode_by_ref(&dt, &mut Vec::new()).err().unwrap();
+///     assert_eq!(e.to_string(), format!("{} is out of the range of a mysql datetime", dt));
+/// }
+/// ```
+impl Encode<'_, MySql> for Datetime {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        let out_of_range = || format!("{} is out of the range of a mysql datetime", self).into();
+        if self.is_infinite() {
+            return Err(out_of_range());
+        }
+        let mut dt = *self;
+        dt.round_subsec(6);
+        if !(0..=9999).contains(&dt.year) {
+            return Err(out_of_range());
+        }
+        let len = match (dt.hour, dt.minute, dt.second, dt.nanosecond) {
+            (0, 0, 0, 0) => 4,
+            (_, _, _, 0) => 7,
+            _ => 11,
+        };
+        buf.push(len);
+        buf.extend_from_slice(&(dt.year as u16).to_le_bytes());
+        buf.extend_from_slice(&[dt.month, dt.day]);
+        if len > 4 {
+            buf.extend_from_slice(&[dt.hour, dt.minute, dt.second]);
+        }
+        if len > 7 {
+            buf.extend_from_slice(&(dt.nanosecond / 1000).to_le_bytes());
+        }
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        12
+    }
+}
+
+/// a zero date such as '0000-00-00 00:00:00' or a partial zero date such as '2020-00-00',
+/// which the tables of a server without NO_ZERO_DATE or NO_ZERO_IN_DATE may hold, is read as
+/// Datetime::default(). Read [`MySqlOption`] for none or [`MySqlNonZero`] for an error instead.
+/// MySQL sends a TIMESTAMP in the session time_zone, which sqlx sets to '+00:00' on connect
+/// so the value is utc. Read it as [`MySqlUtc`] when the session keeps another zone
+impl<'r> Decode<'r, MySql> for Datetime {
+    fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(decode_nonzero(value)?.unwrap_or_default())
+    }
+}
+
+/// the Datetime of a value, or the description of a zero date
+fn decode_nonzero(value: MySqlValueRef<'_>) -> Result<Result<Datetime, String>, BoxDynError> {
+    let ty = value.type_info().to_string();
+    parse_nonzero(&ty, <&[u8] as Decode<MySql>>::decode(value)?)
+}
+
+/// the Datetime of the bytes of a value of type `ty`, or the description of a zero date
+fn parse_nonzero(ty: &str, buf: &[u8]) -> Result<Result<Datetime, String>, BoxDynError> {
+    let dt = match ty {
+        "TIMESTAMP" | "DATETIME" | "DATE" => match binary(buf, &[0, 4, 7, 11]) {
+            Some(binary) => decode_binary(binary),
+            None => {
+                let s = std::str::from_utf8(buf)?;
+                Datetime::from_str(s).ok_or_else(|| format!("{:?} is not a mysql {}", s, ty))?
+            }
+        },
+        _ => {
+            let s = std::str::from_utf8(buf)?;
+            return Ok(Ok(Datetime::from_rfc3339(s)
+                .or_else(|| Datetime::from_str(s))
+                .unwrap_or_default()));
+        }
+    };
+    if dt.month == 0 || dt.day == 0 {
+        return Ok(Err(format!("{:?} is a mysql zero date", buf)));
+    }
+    if !dt.is_valid() {
+        return Err(format!("{:?} is not a mysql {}", buf, ty).into());
+    }
+    Ok(Ok(dt))
+}
+
+/// the value of the binary protocol, one of the `lengths` followed by that many bytes,
+/// or nothing at all for a zero value. The text protocol starts with a digit or a sign instead
+fn binary<'a>(buf: &'a [u8], lengths: &[u8]) -> Option<&'a [u8]> {
+    match buf.split_first() {
+        None => Some(buf),
+        Some((len, rest)) if lengths.contains(len) && rest.len() == *len as usize => Some(rest),
+        _ => None,
+    }
+}
+
+/// the date, the time and the microseconds of a binary value as far as it has them,
+/// all zero when it is empty
+fn decode_binary(buf: &[u8]) -> Datetime {
+    let mut dt = Datetime::default();
+    if let [y0, y1, month, day, ..] = *buf {
+        dt.year = u16::from_le_bytes([y0, y1]) as i64;
+        dt.month = month;
+        dt.day = day;
+    }
+    if let [_, _, _, _, hour, minute, second, ..] = *buf {
+        dt.hour = hour;
+        dt.minute = minute;
+        dt.second = second;
+    }
+    if let [_, _, _, _, _, _, _, m0, m1, m2, m3] = *buf {
+        dt.nanosecond = u32::from_le_bytes([m0, m1, m2, m3]).saturating_mul(1000);
+    }
+    dt
+}
+
+/// a Datetime read from mysql that is none for NULL and for a zero date.
+/// `Option<Datetime>` is none only for NULL and the zero dates the server sends as an empty
+/// binary value
+/// ```no_run
+/// # use sqlx::Row;
+/// # use sys_datetime::MySqlOption;
+/// # async fn f(pool: sqlx::MySqlPool) -> sqlx::Result<()> {
+/// let row = sqlx::query("SELECT created FROM legacy").fetch_one(&pool).await?;
+/// let created: MySqlOption = row.try_get("created")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct MySqlOption(pub Option<Datetime>);
+
+impl Type<MySql> for MySqlOption {
+    fn type_info() -> MySqlTypeInfo {
+        <Datetime as Type<MySql>>::type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        <Datetime as Type<MySql>>::compatible(ty)
+    }
+}
+
+impl<'r> Decode<'r, MySql> for MySqlOption {
+    fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
+        match decode_nonzero(value) {
+            Ok(dt) => Ok(MySqlOption(dt.ok())),
+            Err(e) if e.is::<UnexpectedNullError>() => Ok(MySqlOption(None)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// a Datetime read from mysql for which a zero date is an error
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct MySqlNonZero(pub Datetime);
+
+impl Type<MySql> for MySqlNonZero {
+    fn type_info() -> MySqlTypeInfo {
+        <Datetime as Type<MySql>>::type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        <Datetime as Type<MySql>>::compatible(ty)
+    }
+}
+
+impl<'r> Decode<'r, MySql> for MySqlNonZero {
+    fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(MySqlNonZero(decode_nonzero(value)??))
+    }
+}
+
+/// a Datetime bound and read as a mysql DATE, the time of day is dropped
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct MySqlDate(pub Datetime);
+
+impl Type<MySql> for MySqlDate {
+    fn type_info() -> MySqlTypeInfo {
+        <Date as Type<MySql>>::type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        *ty == Self::type_info()
+    }
+}
+
+impl Encode<'_, MySql> for MySqlDate {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        let mut day = self.0;
+        if !day.is_infinite() {
+            day = Datetime::from_ymd(day.year, day.month, day.day);
+        }
+        Encode::<MySql>::encode_by_ref(&day, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        5
+    }
+}
+
+impl<'r> Decode<'r, MySql> for MySqlDate {
+    fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
+        <Datetime as Decode<MySql>>::decode(value).map(MySqlDate)
+    }
+}
+
+/// the largest magnitude of a mysql TIME, 838:59:59
+const TIME_MAX: i64 = (838 * 3600 + 59 * 60 + 59) * 1_000_000;
+
+impl Type<MySql> for Span {
+    fn type_info() -> MySqlTypeInfo {
+        <MySqlTime as Type<MySql>>::type_info()
+    }
+}
+
+/// a signed mysql TIME from -838:59:59 to 838:59:59, days count as 24 hours and
+/// months are an error
+/// ```
+/// # use sqlx::{Encode, MySql};
+/// # use sys_datetime::Span;
+/// let mut buf = Vec::new();
+/// let span = Span::from_str("-838:59:59").unwrap();
+/// Encode::<MySql>::encode_by_ref(&span, &mut buf).unwrap();
+/// assert_eq!(buf, [8, 1, 34, 0, 0, 0, 22, 59, 59]);
+///
+/// assert!(Encode::<MySql>::encode_by_ref(&Span::from_str("839:00:00").unwrap(), &mut buf).is_err());
+/// assert!(Encode::<MySql>::encode_by_ref(&Span::new(0, i32::MAX, 0), &mut buf).is_err());
+/// ```
+impl Encode<'_, MySql> for Span {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        let micros = self
+            .days()
+            .checked_mul(86_400_000_000)
+            .and_then(|micros| micros.checked_add(self.microseconds()));
+        let micros = match micros {
+            Some(micros) if self.months() == 0 && micros.abs() <= TIME_MAX => micros,
+            _ => return Err(format!("{} is out of the range of a mysql time", self).into()),
+        };
+        let abs = micros.unsigned_abs();
+        let seconds = abs / 1_000_000;
+        let len = match (seconds, abs % 1_000_000) {
+            (0, 0) => 0,
+            (_, 0) => 8,
+            _ => 12,
+        };
+        buf.push(len);
+        if len > 0 {
+            buf.push((micros < 0) as u8);
+            buf.extend_from_slice(&((seconds / 86400) as u32).to_le_bytes());
+            buf.extend_from_slice(&[
+                (seconds / 3600 % 24) as u8,
+                (seconds / 60 % 60) as u8,
+                (seconds % 60) as u8,
+            ]);
+        }
+        if len > 8 {
+            buf.extend_from_slice(&((abs % 1_000_000) as u32).to_le_bytes());
+        }
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        13
+    }
+}
+
+/// the days of a binary TIME are folded into the microseconds
+impl<'r> Decode<'r, MySql> for Span {
+    fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
+        let buf = <&[u8] as Decode<MySql>>::decode(value)?;
+        let Some(binary) = binary(buf, &[0, 8, 12]) else {
+            let s = std::str::from_utf8(buf)?;
+            return Span::from_str(s).ok_or_else(|| format!("{:?} is not a mysql TIME", s).into());
+        };
+        let mut micros = 0;
+        if let [negative, d0, d1, d2, d3, hour, minute, second, ..] = *binary {
+            let days = u32::from_le_bytes([d0, d1, d2, d3]) as i64;
+            micros =
+                ((days * 24 + hour as i64) * 3600 + minute as i64 * 60 + second as i64) * 1_000_000;
+            if let [_, _, _, _, _, _, _, _, m0, m1, m2, m3] = *binary {
+                micros += u32::from_le_bytes([m0, m1, m2, m3]) as i64;
+            }
+            if negative == 1 {
+                micros = -micros;
+            }
+        }
+        Ok(Span::new(0, 0, micros))
+    }
+}
+
+/// a utc Datetime bound and read as a mysql TIMESTAMP of a session whose time_zone is not
+/// '+00:00', MySQL converts a TIMESTAMP to and from the session time_zone in both protocols.
+/// It holds the local time of the session and the offset of its time_zone in seconds east of
+/// utc. A value read has offset 0 until it is given the offset of its session with
+/// `at_offset`. A named zone with daylight saving time changes its offset, so read the offset
+/// again with the value, or set time_zone to an offset. Infinite values are an error
+/// ```
+/// # use sqlx::{Encode, MySql};
+/// # use sys_datetime::{Datetime, MySqlUtc};
+/// let offset = 19800; // time_zone = '+05:30'
+/// let utc = Datetime::from_str("2024-05-06 05:08:09").unwrap();
+/// let local = Datetime::from_str("2024-05-06 10:38:09").unwrap();
+/// let (mut a, mut b) = (Vec::new(), Vec::new());
+/// assert!(Encode::<MySql>::encode_by_ref(&MySqlUtc::from_utc(utc, offset), &mut a).is_ok());
+/// assert!(Encode::<MySql>::encode_by_ref(&local, &mut b).is_ok());
+/// assert_eq!(a, b);
+/// assert_eq!(MySqlUtc::from_utc(local, 0).at_offset(offset).utc(), utc);
+///
+/// let infinity = MySqlUtc::from_utc(Datetime::INFINITY, offset);
+/// assert!(Encode::<MySql>::encode_by_ref(&infinity, &mut a).is_err());
+/// ```
+/// The offset of the session, read on the connection the value is read on
+/// ```no_run
+/// # use sqlx::Row;
+/// # use sys_datetime::MySqlUtc;
+/// # async fn f(pool: sqlx::MySqlPool) -> sqlx::Result<()> {
+/// let mut conn = pool.acquire().await?;
+/// let offset: i64 =
+///     sqlx::query_scalar("SELECT CAST(TIME_TO_SEC(TIMEDIFF(NOW(), UTC_TIMESTAMP())) AS SIGNED)")
+///         .fetch_one(&mut *conn)
+///         .await?;
+/// let row = sqlx::query("SELECT created FROM event").fetch_one(&mut *conn).await?;
+/// let created = row.try_get::<MySqlUtc, _>("created")?.at_offset(offset as i32).utc();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct MySqlUtc {
+    local: Datetime,
+    offset: i32,
+}
+
+impl MySqlUtc {
+    /// the instant `utc` for a session `offset` seconds east of utc
+    pub fn from_utc(utc: Datetime, offset: i32) -> Self {
+        let mut local = utc;
+        if local.is_valid() {
+            local.add_seconds(offset as i64);
+        }
+        Self { local, offset }
+    }
+
+    /// the same local time for a session `offset` seconds east of utc
+    pub fn at_offset(self, offset: i32) -> Self {
+        Self { offset, ..self }
+    }
+
+    /// the instant in utc, a zero date is kept as it is
+    pub fn utc(&self) -> Datetime {
+        let mut utc = self.local;
+        if utc.is_valid() {
+            utc.add_seconds(-self.offset as i64);
+        }
+        utc
+    }
+
+    /// the local time of the session
+    #[inline(always)]
+    pub fn local(&self) -> Datetime {
+        self.local
+    }
+
+    /// the time_zone of the session in seconds east of utc
+    #[inline(always)]
+    pub fn offset(&self) -> i32 {
+        self.offset
+    }
+}
+
+impl Type<MySql> for MySqlUtc {
+    fn type_info() -> MySqlTypeInfo {
+        <OffsetDateTime as Type<MySql>>::type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        matches!(ty.to_string().as_str(), "TIMESTAMP" | "DATETIME")
+    }
+}
+
+impl Encode<'_, MySql> for MySqlUtc {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        if self.local.is_infinite() {
+            return Err(format!("{} is out of the range of a mysql timestamp", self.local).into());
+        }
+        Encode::<MySql>::encode_by_ref(&self.local, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        12
+    }
+}
+
+/// the local time of the session with offset 0, a zero date is kept as it is
+impl<'r> Decode<'r, MySql> for MySqlUtc {
+    fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
+        let local = <Datetime as Decode<MySql>>::decode(value)?;
+        Ok(MySqlUtc { local, offset: 0 })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(s: &str) -> Datetime {
+        Datetime::from_str(s).unwrap()
+    }
+
+    /// the Datetime of a value, or None for a zero date
+    fn parse(ty: &str, buf: &[u8]) -> Option<Datetime> {
+        parse_nonzero(ty, buf).unwrap().ok()
+    }
+
+    #[test]
+    fn binary_lengths() {
+        assert_eq!(parse("DATETIME", &[]), None);
+        assert_eq!(parse("DATE", &[4, 232, 7, 5, 6]), Some(dt("2024-05-06")));
+        assert_eq!(
+            parse("DATETIME", &[7, 232, 7, 5, 6, 7, 8, 9]),
+            Some(dt("2024-05-06 07:08:09"))
+        );
+        assert_eq!(
+            parse("TIMESTAMP", &[11, 232, 7, 5, 6, 7, 8, 9, 32, 161, 7, 0]),
+            Some(dt("2024-05-06 07:08:09.5"))
+        );
+    }
+
+    #[test]
+    fn text_values() {
+        assert_eq!(
+            parse("DATETIME", b"2024-05-06 07:08:09.5"),
+            Some(dt("2024-05-06 07:08:09.5"))
+        );
+        assert_eq!(
+            parse("VARCHAR", b"2024-05-06T07:08:09Z"),
+            Some(dt("2024-05-06 07:08:09"))
+        );
+        assert!(parse_nonzero("DATETIME", b"not a date").is_err());
+    }
+
+    #[test]
+    fn zero_dates() {
+        assert_eq!(parse("DATETIME", b"0000-00-00 00:00:00"), None);
+        assert_eq!(parse("DATE", b"2020-00-00"), None);
+        assert_eq!(parse("DATE", &[4, 228, 7, 0, 0]), None);
+        assert_eq!(parse("DATE", &[4, 228, 7, 1, 0]), None);
+        assert!(parse_nonzero("DATE", &[4, 228, 7, 2, 30]).is_err());
+    }
+
+    #[test]
+    fn session_time_zone() {
+        let local = parse("TIMESTAMP", &[7, 232, 7, 5, 6, 10, 38, 9]).unwrap();
+        let utc = |local, offset| MySqlUtc { local, offset: 0 }.at_offset(offset).utc();
+        assert_eq!(utc(local, 19800), dt("2024-05-06 05:08:09"));
+        assert_eq!(utc(local, -14400), dt("2024-05-06 14:38:09"));
+        assert_eq!(
+            utc(dt("2024-01-01 01:00:00"), 7200),
+            dt("2023-12-31 23:00:00")
+        );
+        assert_eq!(utc(local, 0), local);
+        assert_eq!(MySqlUtc::from_utc(utc(local, 19800), 19800).local(), local);
+
+        let zero = parse_nonzero("TIMESTAMP", &[]).unwrap().unwrap_or_default();
+        assert_eq!(utc(zero, 19800), Datetime::default());
+        let partial = parse_nonzero("DATE", b"2020-00-00")
+            .unwrap()
+            .unwrap_or_default();
+        assert_eq!(utc(partial, 19800), Datetime::default());
+        assert_eq!(utc(Datetime::INFINITY, 19800), Datetime::INFINITY);
+    }
+}
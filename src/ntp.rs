@@ -0,0 +1,127 @@
+use std::time::Duration;
+
+use crate::{offset_count, Datetime};
+
+/// seconds from the ntp prime epoch 1900-01-01 00:00:00 to the unix epoch
+const NTP_UNIX_OFFSET: i64 = 2208988800;
+
+/// seconds in one ntp era, the span of the 32-bit seconds field
+const ERA_SECONDS: i64 = 1 << 32;
+
+impl Datetime {
+    /// seconds since the ntp prime epoch 1900-01-01 00:00:00, across eras,
+    /// i64::MAX for INFINITY and i64::MIN for NEG_INFINITY
+    pub fn ntp_seconds(&self) -> i64 {
+        offset_count(self.unix_seconds(), NTP_UNIX_OFFSET)
+    }
+
+    /// the ntp era, era 0 starts 1900-01-01 and era 1 starts 2036-02-07 06:28:16
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// assert_eq!(Datetime::from_rfc3339("2036-02-07 06:28:15").unwrap().ntp_era(), 0);
+    /// assert_eq!(Datetime::from_rfc3339("2036-02-07 06:28:16").unwrap().ntp_era(), 1);
+    /// ```
+    pub fn ntp_era(&self) -> i64 {
+        self.ntp_seconds().div_euclid(ERA_SECONDS)
+    }
+
+    /// 64-bit ntp timestamp, 32-bit seconds within the era and a 32-bit fraction
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// let dt = Datetime::from_rfc3339("1970-01-01 00:00:00.5").unwrap();
+    /// assert_eq!(dt.to_ntp(), (2208988800 << 32) | 0x8000_0000);
+    /// ```
+    pub fn to_ntp(&self) -> u64 {
+        let seconds = self.ntp_seconds().rem_euclid(ERA_SECONDS) as u64;
+        let fraction = ((self.nanosecond as u64) << 32) / 1_000_000_000;
+        (seconds << 32) | fraction
+    }
+
+    /// create from a 64-bit ntp timestamp of the given era,
+    /// none if the era is too far from era 0 for its seconds to fit in an i64
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// let dt = Datetime::from_ntp_era(1, 0).unwrap();
+    /// assert_eq!(dt.to_string(), "2036-02-07 06:28:16");
+    /// assert_eq!(Datetime::from_ntp_era(i64::MAX, 0), None);
+    /// assert_eq!(Datetime::from_ntp_era(-(1 << 31), 0), None);
+    /// ```
+    pub fn from_ntp_era(era: i64, timestamp: u64) -> Option<Self> {
+        let unix_seconds = era
+            .checked_mul(ERA_SECONDS)?
+            .checked_add((timestamp >> 32) as i64)?
+            .checked_sub(NTP_UNIX_OFFSET)?;
+        Some(Self::from_ntp_seconds(unix_seconds, timestamp))
+    }
+
+    /// the Datetime `unix_seconds` after the unix epoch with the fraction of `timestamp`
+    fn from_ntp_seconds(unix_seconds: i64, timestamp: u64) -> Self {
+        let fraction = timestamp & 0xffff_ffff;
+        let mut dt = Self::from_unix_seconds(unix_seconds);
+        dt.nanosecond = ((fraction * 1_000_000_000 + (1 << 31)) >> 32).min(999_999_999) as u32;
+        dt
+    }
+
+    /// create from a 64-bit ntp timestamp, the era is taken from the most significant bit as
+    /// RFC 4330 suggests, so the timestamp covers 1968-01-20 03:14:08 to 2104-02-26 09:42:23
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// assert_eq!(
+    ///     Datetime::from_ntp(0x8000_0000 << 32).to_string(),
+    ///     "1968-01-20 03:14:08"
+    /// );
+    /// assert_eq!(
+    ///     Datetime::from_ntp(0).to_string(),
+    ///     "2036-02-07 06:28:16"
+    /// );
+    /// ```
+    pub fn from_ntp(timestamp: u64) -> Self {
+        let era = if timestamp >> 63 == 1 { 0 } else { 1 };
+        Self::from_ntp_seconds(
+            era * ERA_SECONDS + (timestamp >> 32) as i64 - NTP_UNIX_OFFSET,
+            timestamp,
+        )
+    }
+
+    /// create from a 64-bit ntp timestamp, the era is chosen so the result is closest to `reference`,
+    /// an infinite reference or one too far for an era is returned
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// let reference = Datetime::from_rfc3339("2200-01-01").unwrap();
+    /// let dt = Datetime::from_rfc3339("2180-06-01 12:00:00.25").unwrap();
+    /// assert_eq!(Datetime::from_ntp_near(dt.to_ntp(), reference), dt);
+    /// ```
+    pub fn from_ntp_near(timestamp: u64, reference: Datetime) -> Self {
+        if reference.is_infinite() {
+            return reference;
+        }
+        let offset = reference.ntp_seconds() - (timestamp >> 32) as i64;
+        let era = (offset + ERA_SECONDS / 2).div_euclid(ERA_SECONDS);
+        Self::from_ntp_era(era, timestamp).unwrap_or(reference)
+    }
+}
+
+/// ntp short format, 16-bit seconds and a 16-bit fraction, used for root delay and dispersion
+/// ```
+/// # use std::time::Duration;
+/// # use sys_datetime::NtpShort;
+/// let short = NtpShort::from_duration(Duration::from_millis(1500));
+/// assert_eq!(short, NtpShort(0x0001_8000));
+/// assert_eq!(short.as_duration(), Duration::from_millis(1500));
+/// ```
+#[derive(Clone, Copy, Default, Debug, PartialEq, PartialOrd)]
+pub struct NtpShort(pub u32);
+
+impl NtpShort {
+    /// convert from a duration, saturating at 65535.99998 seconds
+    pub fn from_duration(duration: Duration) -> Self {
+        let units = (duration.as_nanos() << 16) / 1_000_000_000;
+        Self(units.min(u32::MAX as u128) as u32)
+    }
+
+    /// convert to a duration
+    pub fn as_duration(&self) -> Duration {
+        let nanos = ((self.0 as u64 & 0xffff) * 1_000_000_000 + (1 << 15)) >> 16;
+        Duration::new((self.0 >> 16) as u64, nanos.min(999_999_999) as u32)
+    }
+}
@@ -0,0 +1,160 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use crate::Datetime;
+
+/// an instant with the utc offset of the local time it is shown in.
+/// Two values are equal when they are the same instant, whatever their offsets
+/// ```
+/// # use sys_datetime::{Datetime, OffsetDatetime};
+/// let odt = OffsetDatetime::from_rfc3339("2024-05-06T07:08:09.5+02:00").unwrap();
+/// assert_eq!(odt.utc().to_string(), "2024-05-06 05:08:09.5");
+/// assert_eq!(odt.offset(), 7200);
+/// assert_eq!(odt.to_string(), "2024-05-06 07:08:09.5+02:00");
+///
+/// let utc = Datetime::from_str("2024-05-06 05:08:09.5").unwrap();
+/// assert_eq!(OffsetDatetime::from_utc(utc, -14400).to_string(), "2024-05-06 01:08:09.5-04:00");
+/// assert_eq!(OffsetDatetime::from_utc(utc, 0), odt);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct OffsetDatetime {
+    utc: Datetime,
+    offset: i32,
+}
+
+impl OffsetDatetime {
+    /// the instant `utc` shown at `offset` seconds east of utc
+    pub fn from_utc(utc: Datetime, offset: i32) -> Self {
+        Self { utc, offset }
+    }
+
+    /// the local time `local` at `offset` seconds east of utc
+    pub fn from_local(local: Datetime, offset: i32) -> Self {
+        let mut utc = local;
+        if !utc.is_infinite() {
+            utc.add_seconds(-offset as i64);
+        }
+        Self { utc, offset }
+    }
+
+    /// create from rfc3339 string such as "2024-05-06T07:08:09+02:00" or "2024-05-06 05:08:09Z",
+    /// without an offset the time is utc
+    pub fn from_rfc3339(rfc: &str) -> Option<Self> {
+        let utc = Datetime::from_rfc3339(rfc)?;
+        let tail = rfc.get(19..).unwrap_or_default();
+        let offset = match tail.find(['+', '-']) {
+            Some(p) => {
+                let mut hm = tail[p + 1..].split(':');
+                let hours: i32 = hm.next()?.parse().ok()?;
+                let minutes: i32 = hm.next().map_or(Some(0), |m| m.parse().ok())?;
+                let seconds = hours * 3600 + minutes * 60;
+                if &tail[p..p + 1] == "-" {
+                    -seconds
+                } else {
+                    seconds
+                }
+            }
+            None => 0,
+        };
+        Some(Self { utc, offset })
+    }
+
+    /// the instant in utc
+    #[inline(always)]
+    pub fn utc(&self) -> Datetime {
+        self.utc
+    }
+
+    /// seconds east of utc
+    #[inline(always)]
+    pub fn offset(&self) -> i32 {
+        self.offset
+    }
+
+    /// the local time at the offset
+    pub fn local(&self) -> Datetime {
+        let mut local = self.utc;
+        if !local.is_infinite() {
+            local.add_seconds(self.offset as i64);
+        }
+        local
+    }
+
+    /// the same instant shown at another offset
+    pub fn to_offset(&self, offset: i32) -> Self {
+        Self::from_utc(self.utc, offset)
+    }
+}
+
+impl From<Datetime> for OffsetDatetime {
+    /// a Datetime taken as utc
+    fn from(utc: Datetime) -> Self {
+        Self::from_utc(utc, 0)
+    }
+}
+
+impl PartialEq for OffsetDatetime {
+    fn eq(&self, other: &Self) -> bool {
+        self.utc == other.utc
+    }
+}
+
+impl PartialOrd for OffsetDatetime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.utc.partial_cmp(&other.utc)
+    }
+}
+
+impl Display for OffsetDatetime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.utc.is_infinite() {
+            return write!(f, "{}", self.utc);
+        }
+        let local = self.local().to_string();
+        let (local, bc) = match local.strip_suffix(" BC") {
+            Some(local) => (local, " BC"),
+            None => (local.as_str(), ""),
+        };
+        write!(f, "{}{}{}", local, format_offset(self.offset), bc)
+    }
+}
+
+/// seconds east of utc of "+02", "-05:30", "+05:30:15", "+0530" or "UTC"
+pub(crate) fn parse_offset(zone: &str) -> Option<i32> {
+    if matches!(zone, "UTC" | "GMT" | "Z") {
+        return Some(0);
+    }
+    let sign = match zone.as_bytes().first()? {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let digits = &zone[1..];
+    let fields: Vec<&str> = if digits.len() > 2 && !digits.contains(':') {
+        // the basic format "+hhmm" or "+hhmmss" of a zone abbreviation such as "<+0530>"
+        if !digits.len().is_multiple_of(2) {
+            return None;
+        }
+        (0..digits.len())
+            .step_by(2)
+            .filter_map(|i| digits.get(i..i + 2))
+            .collect()
+    } else {
+        digits.split(':').collect()
+    };
+    let mut seconds = 0;
+    for (i, field) in fields.into_iter().enumerate() {
+        seconds += field.parse::<i32>().ok()? * [3600, 60, 1].get(i)?;
+    }
+    Some(sign * seconds)
+}
+
+/// "+hh:mm" or "+hh:mm:ss" of seconds east of utc
+pub(crate) fn format_offset(offset: i32) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let abs = offset.unsigned_abs();
+    let mut s = format!("{}{:0>2}:{:0>2}", sign, abs / 3600, abs / 60 % 60);
+    if !abs.is_multiple_of(60) {
+        s += &format!(":{:0>2}", abs % 60);
+    }
+    s
+}
@@ -0,0 +1,190 @@
+use std::fmt::Display;
+
+use crate::{Datetime, JulianDate};
+
+const MONTHS_EN: [&str; 12] = [
+    "Farvardin",
+    "Ordibehesht",
+    "Khordad",
+    "Tir",
+    "Mordad",
+    "Shahrivar",
+    "Mehr",
+    "Aban",
+    "Azar",
+    "Dey",
+    "Bahman",
+    "Esfand",
+];
+
+const MONTHS_FA: [&str; 12] = [
+    "فروردین",
+    "اردیبهشت",
+    "خرداد",
+    "تیر",
+    "مرداد",
+    "شهریور",
+    "مهر",
+    "آبان",
+    "آذر",
+    "دی",
+    "بهمن",
+    "اسفند",
+];
+
+/// a date of the arithmetical persian (solar hijri, jalali) calendar, the first six months have
+/// 31 days, the next five 30 and Esfand 29 or 30 in a leap year
+/// ```
+/// # use sys_datetime::{Datetime, PersianDate};
+/// let dt = Datetime::from_str("2024-03-20 00:00:00").unwrap();
+/// let pd = PersianDate::from_datetime(&dt).unwrap();
+/// assert_eq!(pd.to_string(), "1403-01-01 AP");
+/// assert_eq!(pd.format_en(), "1 Farvardin 1403 AP");
+/// assert_eq!(pd.format_fa(), "۱ فروردین ۱۴۰۳");
+/// assert_eq!(pd.to_datetime(), dt);
+///
+/// let pd = PersianDate::new(1403, 12, 30).unwrap();
+/// assert_eq!(pd.to_datetime().to_string(), "2025-03-20 00:00:00");
+/// assert_eq!(PersianDate::new(1402, 12, 30), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PersianDate {
+    year: i64,
+    month: u8,
+    day: u8,
+}
+
+impl PersianDate {
+    /// none if the date does not exist
+    pub fn new(year: i64, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || day < 1 || day > month_days(year, month) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    #[inline(always)]
+    pub fn year(&self) -> i64 {
+        self.year
+    }
+
+    #[inline(always)]
+    pub fn month(&self) -> i64 {
+        self.month as i64
+    }
+
+    #[inline(always)]
+    pub fn day(&self) -> i64 {
+        self.day as i64
+    }
+
+    /// whether Esfand has 30 days, 8 years in every 33. The rule agrees with the astronomical
+    /// calendar observed in iran until 1501 AP (2123)
+    pub fn is_leap_year(year: i64) -> bool {
+        (25 * year + 11).rem_euclid(33) < 8
+    }
+
+    /// the persian date of the day of `dt`, the time of day is ignored.
+    /// Years before 1 AP are counted proleptically with 0 and negative numbers.
+    /// none for INFINITY and NEG_INFINITY
+    pub fn from_datetime(dt: &Datetime) -> Option<Self> {
+        if dt.is_infinite() {
+            return None;
+        }
+        let days = dt.unix_days();
+        // 12053 days in 33 years
+        let mut year = (33 * (days - epoch()) + 3).div_euclid(12053) + 1;
+        while year_start(year + 1) <= days {
+            year += 1;
+        }
+        while year_start(year) > days {
+            year -= 1;
+        }
+        let doy = days - year_start(year);
+        let (month, day) = if doy < 186 {
+            (doy / 31 + 1, doy % 31 + 1)
+        } else {
+            ((doy - 186) / 30 + 7, (doy - 186) % 30 + 1)
+        };
+        Some(Self {
+            year,
+            month: month as u8,
+            day: day as u8,
+        })
+    }
+
+    /// midnight of this day in the gregorian calendar
+    pub fn to_datetime(&self) -> Datetime {
+        let month = self.month as i64;
+        let before = if month <= 7 {
+            31 * (month - 1)
+        } else {
+            30 * (month - 1) + 6
+        };
+        Datetime::from_unix_days(year_start(self.year) + before + self.day as i64 - 1)
+    }
+
+    /// the english name of the month such as "Farvardin"
+    pub fn month_name_en(&self) -> &'static str {
+        MONTHS_EN[self.month as usize - 1]
+    }
+
+    /// the persian name of the month such as "فروردین"
+    pub fn month_name_fa(&self) -> &'static str {
+        MONTHS_FA[self.month as usize - 1]
+    }
+
+    /// such as "1 Farvardin 1403 AP"
+    pub fn format_en(&self) -> String {
+        format!("{} {} {} AP", self.day, self.month_name_en(), self.year)
+    }
+
+    /// such as "۱ فروردین ۱۴۰۳" with persian digits
+    pub fn format_fa(&self) -> String {
+        format!(
+            "{} {} {}",
+            persian_digits(self.day as i64),
+            self.month_name_fa(),
+            persian_digits(self.year)
+        )
+    }
+}
+
+impl Display for PersianDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:0>4}-{:0>2}-{:0>2} AP",
+            self.year, self.month, self.day
+        )
+    }
+}
+
+/// unix days of 1 Farvardin 1 AP, 622-03-18 julian in the 33 year arithmetic,
+/// a day before the epoch of the astronomical calendar
+fn epoch() -> i64 {
+    JulianDate::new(622, 3, 18).map_or(0, |jd| jd.to_datetime().unix_days())
+}
+
+/// unix days of 1 Farvardin of the year
+fn year_start(year: i64) -> i64 {
+    epoch() + 365 * (year - 1) + (8 * year + 21).div_euclid(33)
+}
+
+fn month_days(year: i64, month: u8) -> u8 {
+    match month {
+        1..=6 => 31,
+        12 if !PersianDate::is_leap_year(year) => 29,
+        _ => 30,
+    }
+}
+
+fn persian_digits(n: i64) -> String {
+    n.to_string()
+        .chars()
+        .map(|c| match c.to_digit(10) {
+            Some(d) => char::from_u32(0x06F0 + d).unwrap_or(c),
+            None => c,
+        })
+        .collect()
+}
@@ -0,0 +1,1180 @@
+use std::ops::Bound;
+
+use sqlx::{
+    encode::IsNull,
+    error::BoxDynError,
+    postgres::{
+        PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgTypeKind, PgValueFormat, PgValueRef,
+    },
+    Decode, Encode, Postgres, Type, ValueRef,
+};
+
+use crate::{
+    offset::parse_offset, parse_fraction, Datetime, DatetimeRange, Epoch, OffsetDatetime,
+    OffsetTime, Span, TimeOfDay,
+};
+
+/// 4713-01-01 00:00:00 BC, the earliest TIMESTAMP and DATE postgres accepts
+const MIN: Datetime = Datetime::from_ymd(-4713, 1, 1);
+
+/// the day after the latest TIMESTAMP postgres accepts, 294276-12-31 23:59:59.999999
+const TIMESTAMP_END: Datetime = Datetime::from_ymd(294277, 1, 1);
+
+/// the day after the latest DATE postgres accepts, 5874897-12-31
+const DATE_END: Datetime = Datetime::from_ymd(5874898, 1, 1);
+
+/// whether a finite value lies in the range of a postgres type ending before `end`
+fn in_range(dt: &Datetime, end: &Datetime) -> bool {
+    let days = dt.unix_days();
+    days >= MIN.unix_days() && days < end.unix_days()
+}
+
+impl Type<Postgres> for Datetime {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("TIMESTAMP")
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        matches!(
+            ty.to_string().as_str(),
+            "TIMESTAMP" | "TIMESTAMPTZ" | "DATE" | "VARCHAR" | "TEXT"
+        )
+    }
+}
+
+impl PgHasArrayType for Datetime {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_TIMESTAMP")
+    }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        matches!(
+            ty.to_string().as_str(),
+            "TIMESTAMP[]" | "TIMESTAMPTZ[]" | "DATE[]" | "VARCHAR[]" | "TEXT[]"
+        )
+    }
+}
+
+/// microseconds since 2000-01-01 declared as a TIMESTAMP parameter, the fraction is rounded to
+/// microseconds. INFINITY and NEG_INFINITY are sent as 'infinity' and '-infinity',
+/// a value outside 4713 BC to 294276 AD is an error.
+/// The parameter is always a TIMESTAMP. Postgres converts it on assignment to a DATE or TEXT
+/// column, but not in an expression: `WHERE text_col = $1` fails with no operator for
+/// text = timestamp. Bind [`PgText`] to send TEXT and [`PgDate`] to send DATE
+impl Encode<'_, Postgres> for Datetime {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        Encode::<Postgres>::encode_by_ref(&timestamp_micros(self)?, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        8
+    }
+}
+
+/// microseconds since 2000-01-01 of a TIMESTAMP or TIMESTAMPTZ, i64::MAX and i64::MIN for the infinities
+fn timestamp_micros(dt: &Datetime) -> Result<i64, BoxDynError> {
+    if *dt == Datetime::INFINITY {
+        return Ok(i64::MAX);
+    }
+    if *dt == Datetime::NEG_INFINITY {
+        return Ok(i64::MIN);
+    }
+    let mut rounded = *dt;
+    rounded.round_subsec(6);
+    if !in_range(&rounded, &TIMESTAMP_END) {
+        return Err(format!("{} is out of the range of a postgres timestamp", dt).into());
+    }
+    Ok(Epoch::POSTGRES.count(&rounded).unwrap_or_default())
+}
+
+/// A TIMESTAMPTZ is read as its utc instant, whatever the session TimeZone: the binary format
+/// is utc and the offset of the text format is applied.
+/// A Datetime parameter is declared TIMESTAMP, which postgres converts to TIMESTAMPTZ in the
+/// session TimeZone, so it is the utc instant only while the TimeZone is UTC as sqlx sets it
+/// on connect. Bind [`OffsetDatetime`] to write an instant that no TimeZone setting can shift
+impl<'r> Decode<'r, Postgres> for Datetime
+where
+    i64: Decode<'r, Postgres>,
+    i32: Decode<'r, Postgres>,
+    &'r str: Decode<'r, Postgres>,
+{
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        match value.type_info().as_ref().to_string().as_str() {
+            ty @ ("TIMESTAMP" | "TIMESTAMPTZ" | "DATE")
+                if value.format() == PgValueFormat::Text =>
+            {
+                let s = value.as_str()?;
+                parse_text(s).ok_or_else(|| format!("{:?} is not a postgres {}", s, ty).into())
+            }
+            "TIMESTAMP" | "TIMESTAMPTZ" => timestamp_from_micros(i64::decode(value)?),
+            "DATE" => date_from_days(i32::decode(value)?),
+            _ => {
+                let s = <&str>::decode(value)?;
+                Ok(parse_text(s)
+                    .or_else(|| Datetime::from_rfc3339(s))
+                    .or_else(|| Datetime::from_str(s))
+                    .unwrap_or_default())
+            }
+        }
+    }
+}
+
+/// a binary TIMESTAMP or TIMESTAMPTZ
+fn timestamp_from_micros(micros: i64) -> Result<Datetime, BoxDynError> {
+    match micros {
+        i64::MAX => Ok(Datetime::INFINITY),
+        i64::MIN => Ok(Datetime::NEG_INFINITY),
+        micros => match Epoch::POSTGRES.to_datetime(micros) {
+            Some(dt) if in_range(&dt, &TIMESTAMP_END) => Ok(dt),
+            _ => Err(format!(
+                "{} microseconds after 2000-01-01 are out of the range of a postgres timestamp",
+                micros
+            )
+            .into()),
+        },
+    }
+}
+
+/// a binary DATE
+fn date_from_days(days: i32) -> Result<Datetime, BoxDynError> {
+    match days {
+        i32::MAX => Ok(Datetime::INFINITY),
+        i32::MIN => Ok(Datetime::NEG_INFINITY),
+        days => {
+            let dt = Datetime::from_unix_days(Epoch::POSTGRES.origin().unix_days() + days as i64);
+            if in_range(&dt, &DATE_END) {
+                Ok(dt)
+            } else {
+                Err(format!("{} is out of the range of a postgres date", dt).into())
+            }
+        }
+    }
+}
+
+const MONTH_ABBREVIATIONS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// a TIMESTAMP, TIMESTAMPTZ or DATE in the text output of any DateStyle such as
+/// "2024-05-06 07:08:09.5+02", "05/06/2024 07:08:09.50 UTC", "Mon May 06 07:08:09.5 2024 UTC",
+/// "06.05.2024" or "0044-03-15 BC". An offset or a UTC zone moves the value to utc like the
+/// binary format does, other zone abbreviations are ambiguous and rejected. The numeric SQL
+/// and Postgres styles are read month first as sqlx connects with "ISO, MDY", unless the first
+/// field is above 12
+fn parse_text(s: &str) -> Option<Datetime> {
+    parse_local(s).map(|(local, offset)| OffsetDatetime::from_local(local, offset).utc())
+}
+
+/// the local time of a text value and its offset in seconds east of utc, 0 without one
+fn parse_local(s: &str) -> Option<(Datetime, i32)> {
+    let s = s.trim();
+    match s {
+        "infinity" => return Some((Datetime::INFINITY, 0)),
+        "-infinity" => return Some((Datetime::NEG_INFINITY, 0)),
+        _ => {}
+    }
+    let (s, bc) = match s.strip_suffix(" BC") {
+        Some(s) => (s, true),
+        None => (s, false),
+    };
+
+    let mut tokens = s.split_whitespace();
+    let first = tokens.next()?;
+    let (mut year, month, day): (Option<i64>, u8, u8) =
+        if first.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            // postgres style, "Mon May 06" or "Mon 06 May" with the year after the time
+            let (a, b) = (tokens.next()?, tokens.next()?);
+            match month_number(a) {
+                Some(month) => (None, month, b.parse().ok()?),
+                None => (None, month_number(b)?, a.parse().ok()?),
+            }
+        } else {
+            let fields: Vec<&str> = first.split(['-', '/', '.']).collect();
+            let [a, b, c] = fields[..] else {
+                return None;
+            };
+            let (year, month, day) = if first.contains('.') {
+                // german, dd.mm.yyyy
+                (c, b, a)
+            } else if first.contains('-') && a.len() >= 4 {
+                (a, b, c)
+            } else if a.parse::<u8>().ok()? > 12 {
+                (c, b, a)
+            } else {
+                (c, a, b)
+            };
+            (
+                Some(year.parse().ok()?),
+                month.parse().ok()?,
+                day.parse().ok()?,
+            )
+        };
+
+    let mut dt = Datetime::from_ymd(0, month, day);
+    let mut offset = 0;
+    for token in tokens {
+        if token.contains(':') {
+            let (time, zone) = match token.rfind(['+', '-']) {
+                Some(i) if i > 0 => token.split_at(i),
+                _ => (token, ""),
+            };
+            let mut hms = time.split(':');
+            dt.hour = hms.next()?.parse().ok()?;
+            dt.minute = hms.next()?.parse().ok()?;
+            let second = hms.next().unwrap_or("0");
+            let (second, fraction) = second.split_once('.').unwrap_or((second, ""));
+            dt.second = second.parse().ok()?;
+            if !fraction.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            dt.nanosecond = parse_fraction(fraction);
+            if !zone.is_empty() {
+                offset = parse_offset(zone)?;
+            }
+        } else if token.bytes().all(|b| b.is_ascii_digit()) && year.is_none() {
+            year = token.parse().ok();
+        } else {
+            offset = parse_offset(token)?;
+        }
+    }
+    let year = year?;
+    dt.year = if bc { -year } else { year };
+    if !dt.is_valid() {
+        return None;
+    }
+    Some((dt, offset))
+}
+
+fn month_number(name: &str) -> Option<u8> {
+    MONTH_ABBREVIATIONS
+        .iter()
+        .position(|m| name.eq_ignore_ascii_case(m))
+        .map(|i| i as u8 + 1)
+}
+
+/// a Datetime bound and read as a postgres DATE, sent as days since 2000-01-01,
+/// the time of day is dropped. A prepared statement keeps the parameter types of its first
+/// execution, so bind the same type to a statement every time
+/// ```no_run
+/// # use sys_datetime::{Datetime, PgDate};
+/// # async fn f(pool: sqlx::PgPool) -> sqlx::Result<()> {
+/// let day = Datetime::from_str("2024-05-06 00:00:00").unwrap();
+/// sqlx::query("INSERT INTO holiday (day) VALUES ($1)")
+///     .bind(PgDate(day))
+///     .execute(&pool)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct PgDate(pub Datetime);
+
+impl Type<Postgres> for PgDate {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("DATE")
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == Self::type_info()
+    }
+}
+
+impl PgHasArrayType for PgDate {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_DATE")
+    }
+}
+
+impl Encode<'_, Postgres> for PgDate {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let days = if self.0 == Datetime::INFINITY {
+            i32::MAX
+        } else if self.0 == Datetime::NEG_INFINITY {
+            i32::MIN
+        } else {
+            if !in_range(&self.0, &DATE_END) {
+                return Err(format!("{} is out of the range of a postgres date", self.0).into());
+            }
+            (self.0.unix_days() - Epoch::POSTGRES.origin().unix_days()) as i32
+        };
+        Encode::<Postgres>::encode_by_ref(&days, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        4
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for PgDate {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        <Datetime as Decode<Postgres>>::decode(value).map(PgDate)
+    }
+}
+
+/// a Datetime bound and read as postgres TEXT in the format of Display,
+/// also accepted by VARCHAR columns
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct PgText(pub Datetime);
+
+impl Type<Postgres> for PgText {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("TEXT")
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        matches!(ty.to_string().as_str(), "VARCHAR" | "TEXT")
+    }
+}
+
+impl PgHasArrayType for PgText {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_TEXT")
+    }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        matches!(ty.to_string().as_str(), "VARCHAR[]" | "TEXT[]")
+    }
+}
+
+impl Encode<'_, Postgres> for PgText {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        Encode::<Postgres>::encode(self.0.to_string(), buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        35
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for PgText {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        <Datetime as Decode<Postgres>>::decode(value).map(PgText)
+    }
+}
+
+impl Type<Postgres> for OffsetDatetime {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("TIMESTAMPTZ")
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == Self::type_info()
+    }
+}
+
+impl PgHasArrayType for OffsetDatetime {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_TIMESTAMPTZ")
+    }
+}
+
+/// microseconds since 2000-01-01 utc declared as a TIMESTAMPTZ parameter,
+/// the session TimeZone plays no part
+/// ```no_run
+/// # use sys_datetime::OffsetDatetime;
+/// # async fn f(pool: sqlx::PgPool) -> sqlx::Result<()> {
+/// let at = OffsetDatetime::from_rfc3339("2024-05-06T07:08:09+02:00").unwrap();
+/// sqlx::query("INSERT INTO event (at) VALUES ($1)")
+///     .bind(at)
+///     .execute(&pool)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+impl Encode<'_, Postgres> for OffsetDatetime {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        Encode::<Postgres>::encode_by_ref(&timestamp_micros(&self.utc())?, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        8
+    }
+}
+
+/// the binary format has no offset and is read at utc, the text format keeps the offset
+/// the server printed in its TimeZone
+impl<'r> Decode<'r, Postgres> for OffsetDatetime {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        if value.format() == PgValueFormat::Text {
+            let s = value.as_str()?;
+            return parse_local(s)
+                .map(|(local, offset)| OffsetDatetime::from_local(local, offset))
+                .ok_or_else(|| format!("{:?} is not a postgres TIMESTAMPTZ", s).into());
+        }
+        <Datetime as Decode<Postgres>>::decode(value).map(OffsetDatetime::from)
+    }
+}
+
+impl Type<Postgres> for Span {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("INTERVAL")
+    }
+}
+
+impl PgHasArrayType for Span {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_INTERVAL")
+    }
+}
+
+/// microseconds, days and months, none of them folded into another
+impl Encode<'_, Postgres> for Span {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        buf.extend_from_slice(&self.microseconds().to_be_bytes());
+        buf.extend_from_slice(&(self.days() as i32).to_be_bytes());
+        buf.extend_from_slice(&(self.months() as i32).to_be_bytes());
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        16
+    }
+}
+
+/// the text format is read in the default postgres IntervalStyle
+impl<'r> Decode<'r, Postgres> for Span {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        if value.format() == PgValueFormat::Text {
+            let s = value.as_str()?;
+            return Span::from_str(s)
+                .ok_or_else(|| format!("{:?} is not a postgres INTERVAL", s).into());
+        }
+        span_from_bytes(value.as_bytes()?)
+    }
+}
+
+/// a binary INTERVAL
+fn span_from_bytes(buf: &[u8]) -> Result<Span, BoxDynError> {
+    if buf.len() != 16 {
+        return Err(format!("{} bytes is not a postgres INTERVAL", buf.len()).into());
+    }
+    Ok(Span::new(
+        i32::from_be_bytes(be_bytes(&buf[12..])),
+        i32::from_be_bytes(be_bytes(&buf[8..12])),
+        i64::from_be_bytes(be_bytes(&buf[..8])),
+    ))
+}
+
+impl Type<Postgres> for TimeOfDay {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("TIME")
+    }
+}
+
+impl PgHasArrayType for TimeOfDay {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_TIME")
+    }
+}
+
+/// microseconds since midnight, the fraction is rounded to microseconds
+impl Encode<'_, Postgres> for TimeOfDay {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        Encode::<Postgres>::encode_by_ref(&time_micros(self), buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        8
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for TimeOfDay {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        if value.format() == PgValueFormat::Text {
+            let s = value.as_str()?;
+            return TimeOfDay::from_str(s)
+                .ok_or_else(|| format!("{:?} is not a postgres TIME", s).into());
+        }
+        time_from_micros(<i64 as Decode<Postgres>>::decode(value)?)
+    }
+}
+
+impl Type<Postgres> for OffsetTime {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("TIMETZ")
+    }
+}
+
+impl PgHasArrayType for OffsetTime {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_TIMETZ")
+    }
+}
+
+/// microseconds since midnight and the offset in seconds west of utc as postgres keeps it
+impl Encode<'_, Postgres> for OffsetTime {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        buf.extend_from_slice(&time_micros(&self.time()).to_be_bytes());
+        buf.extend_from_slice(&(-self.offset()).to_be_bytes());
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        12
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for OffsetTime {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        if value.format() == PgValueFormat::Text {
+            let s = value.as_str()?;
+            return OffsetTime::from_str(s)
+                .ok_or_else(|| format!("{:?} is not a postgres TIMETZ", s).into());
+        }
+        offset_time_from_bytes(value.as_bytes()?)
+    }
+}
+
+/// a binary TIMETZ
+fn offset_time_from_bytes(buf: &[u8]) -> Result<OffsetTime, BoxDynError> {
+    if buf.len() != 12 {
+        return Err(format!("{} bytes is not a postgres TIMETZ", buf.len()).into());
+    }
+    Ok(OffsetTime::new(
+        time_from_micros(i64::from_be_bytes(be_bytes(&buf[..8])))?,
+        -i32::from_be_bytes(be_bytes(&buf[8..])),
+    ))
+}
+
+const RANGE_EMPTY: u8 = 0x01;
+const RANGE_LB_INC: u8 = 0x02;
+const RANGE_UB_INC: u8 = 0x04;
+const RANGE_LB_INF: u8 = 0x08;
+const RANGE_UB_INF: u8 = 0x10;
+
+impl Type<Postgres> for DatetimeRange {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("TSRANGE")
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        matches!(
+            ty.to_string().as_str(),
+            "TSRANGE" | "TSTZRANGE" | "DATERANGE"
+        )
+    }
+}
+
+impl PgHasArrayType for DatetimeRange {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_TSRANGE")
+    }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        matches!(
+            ty.to_string().as_str(),
+            "TSRANGE[]" | "TSTZRANGE[]" | "DATERANGE[]"
+        )
+    }
+}
+
+impl Type<Postgres> for DatetimeRange<OffsetDatetime> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("TSTZRANGE")
+    }
+}
+
+impl PgHasArrayType for DatetimeRange<OffsetDatetime> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_TSTZRANGE")
+    }
+}
+
+/// postgres makes a DATERANGE start included and end excluded, "[2024-05-01,2024-05-07]" is
+/// read back as "[2024-05-01,2024-05-08)"
+impl Type<Postgres> for DatetimeRange<PgDate> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("DATERANGE")
+    }
+}
+
+impl PgHasArrayType for DatetimeRange<PgDate> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_DATERANGE")
+    }
+}
+
+/// a tsrange of Datetime, a tstzrange of OffsetDatetime or a daterange of PgDate,
+/// each end encoded as the point itself
+/// ```no_run
+/// # use std::ops::Bound;
+/// # use sys_datetime::{DatetimeRange, OffsetDatetime};
+/// # async fn f(pool: sqlx::PgPool) -> sqlx::Result<()> {
+/// let start = OffsetDatetime::from_rfc3339("2024-05-06T09:00:00+02:00").unwrap();
+/// let window = DatetimeRange::new(Bound::Included(start), Bound::Unbounded).unwrap();
+/// sqlx::query("INSERT INTO booking (during) VALUES ($1)")
+///     .bind(window)
+///     .execute(&pool)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+impl<'q, T> Encode<'q, Postgres> for DatetimeRange<T>
+where
+    T: Encode<'q, Postgres> + PartialOrd + Copy,
+{
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let Some((start, end)) = self.bounds() else {
+            buf.push(RANGE_EMPTY);
+            return Ok(IsNull::No);
+        };
+        let mut flags = 0;
+        match start {
+            Bound::Included(_) => flags |= RANGE_LB_INC,
+            Bound::Unbounded => flags |= RANGE_LB_INF,
+            Bound::Excluded(_) => {}
+        }
+        match end {
+            Bound::Included(_) => flags |= RANGE_UB_INC,
+            Bound::Unbounded => flags |= RANGE_UB_INF,
+            Bound::Excluded(_) => {}
+        }
+        buf.push(flags);
+        for bound in [start, end] {
+            if let Bound::Included(point) | Bound::Excluded(point) = bound {
+                // each end is prefixed with its length
+                let at = buf.len();
+                buf.extend_from_slice(&[0; 4]);
+                if let IsNull::Yes = point.encode_by_ref(buf)? {
+                    return Err("a postgres range may not end at null".into());
+                }
+                let len = (buf.len() - at - 4) as i32;
+                buf[at..at + 4].copy_from_slice(&len.to_be_bytes());
+            }
+        }
+        Ok(IsNull::No)
+    }
+}
+
+/// the ends of a TSTZRANGE are utc instants like a Datetime read from a TIMESTAMPTZ
+impl<'r> Decode<'r, Postgres> for DatetimeRange {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        decode_range(value, |local, offset| {
+            OffsetDatetime::from_local(local, offset).utc()
+        })
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for DatetimeRange<OffsetDatetime> {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        decode_range(value, OffsetDatetime::from_local)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for DatetimeRange<PgDate> {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        decode_range(value, |local, _| PgDate(local))
+    }
+}
+
+/// a range whose ends are made from the local time and offset of each end,
+/// the binary format has no offset
+fn decode_range<T: PartialOrd + Copy>(
+    value: PgValueRef<'_>,
+    point: impl Fn(Datetime, i32) -> T,
+) -> Result<DatetimeRange<T>, BoxDynError> {
+    let ty = value.type_info().into_owned();
+    let PgTypeKind::Range(element) = ty.kind() else {
+        return Err(format!("{} is not a postgres range", ty).into());
+    };
+    let range = if value.format() == PgValueFormat::Text {
+        range_from_text(value.as_str()?, point)
+    } else {
+        range_from_bytes(value.as_bytes()?, &element.to_string(), point)
+    };
+    range.map_err(|e| format!("{}, the value is not a postgres {}", e, ty).into())
+}
+
+/// a range in the text format such as "empty" or "[\"2024-05-06 09:00:00\",)"
+fn range_from_text<T: PartialOrd + Copy>(
+    s: &str,
+    point: impl Fn(Datetime, i32) -> T,
+) -> Result<DatetimeRange<T>, String> {
+    if s == "empty" {
+        return Ok(DatetimeRange::EMPTY);
+    }
+    if s.len() < 2 {
+        return Err(format!("{:?} has no bounds", s));
+    }
+    let (lower, inner, upper) = (s.get(..1), s.get(1..s.len() - 1), s.get(s.len() - 1..));
+    let (Some(lower), Some((start, end)), Some(upper)) =
+        (lower, inner.and_then(|inner| inner.split_once(',')), upper)
+    else {
+        return Err(format!("{:?} has no bounds", s));
+    };
+    let bound = |text: &str, included: bool| -> Result<Bound<T>, String> {
+        let text = text.trim_matches('"');
+        if text.is_empty() {
+            return Ok(Bound::Unbounded);
+        }
+        let (local, offset) = parse_local(text).ok_or_else(|| format!("{:?} is no end", text))?;
+        let p = point(local, offset);
+        Ok(if included {
+            Bound::Included(p)
+        } else {
+            Bound::Excluded(p)
+        })
+    };
+    let (start, end) = (bound(start, lower == "[")?, bound(end, upper == "]")?);
+    DatetimeRange::new(start, end).ok_or_else(|| "the start is after the end".into())
+}
+
+/// a range in the binary format, the flags followed by each bounded end prefixed with its
+/// length, the ends are an `element` of TIMESTAMP, TIMESTAMPTZ or DATE
+fn range_from_bytes<T: PartialOrd + Copy>(
+    buf: &[u8],
+    element: &str,
+    point: impl Fn(Datetime, i32) -> T,
+) -> Result<DatetimeRange<T>, String> {
+    let Some((&flags, mut rest)) = buf.split_first() else {
+        return Err("no flags".into());
+    };
+    if flags & RANGE_EMPTY != 0 {
+        return Ok(DatetimeRange::EMPTY);
+    }
+    let mut bound = |unbounded: u8, included: u8| -> Result<Bound<T>, String> {
+        if flags & unbounded != 0 {
+            return Ok(Bound::Unbounded);
+        }
+        let truncated = || format!("{} bytes end early", buf.len());
+        let (len, tail) = rest.split_at_checked(4).ok_or_else(truncated)?;
+        let len = i32::from_be_bytes(be_bytes(len)) as usize;
+        let (bytes, tail) = tail.split_at_checked(len).ok_or_else(truncated)?;
+        rest = tail;
+        let dt = match (element, bytes.len()) {
+            ("TIMESTAMP" | "TIMESTAMPTZ", 8) => {
+                timestamp_from_micros(i64::from_be_bytes(be_bytes(bytes)))
+            }
+            ("DATE", 4) => date_from_days(i32::from_be_bytes(be_bytes(bytes))),
+            _ => return Err(format!("{} bytes is no {}", bytes.len(), element)),
+        }
+        .map_err(|e| e.to_string())?;
+        Ok(if flags & included != 0 {
+            Bound::Included(point(dt, 0))
+        } else {
+            Bound::Excluded(point(dt, 0))
+        })
+    };
+    let start = bound(RANGE_LB_INF, RANGE_LB_INC)?;
+    let end = bound(RANGE_UB_INF, RANGE_UB_INC)?;
+    if !rest.is_empty() {
+        return Err(format!("{} bytes follow the end", rest.len()));
+    }
+    DatetimeRange::new(start, end).ok_or_else(|| "the start is after the end".into())
+}
+
+fn time_micros(time: &TimeOfDay) -> i64 {
+    (time.nanoseconds() + 500) / 1000
+}
+
+fn time_from_micros(micros: i64) -> Result<TimeOfDay, BoxDynError> {
+    micros
+        .checked_mul(1000)
+        .and_then(TimeOfDay::from_nanoseconds)
+        .ok_or_else(|| {
+            format!(
+                "{} microseconds is out of the range of a postgres time",
+                micros
+            )
+            .into()
+        })
+}
+
+/// a big endian field of a binary value whose length was checked
+fn be_bytes<const N: usize>(field: &[u8]) -> [u8; N] {
+    field.try_into().unwrap_or([0; N])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// the bytes of a value in the binary format
+    fn encode<'q, T: Encode<'q, Postgres>>(value: T) -> Result<Vec<u8>, BoxDynError> {
+        let mut buf = PgArgumentBuffer::default();
+        match value.encode_by_ref(&mut buf)? {
+            IsNull::No => Ok(buf.to_vec()),
+            IsNull::Yes => Err("null".into()),
+        }
+    }
+
+    /// a hex string of the form psql prints a bytea in
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    /// the bytes of a hex string
+    fn hex_bytes(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn dt(s: &str) -> Datetime {
+        Datetime::from_str(s).unwrap()
+    }
+
+    /// the utc value of a text value in the format of Display
+    fn text(s: &str) -> Option<String> {
+        parse_text(s).map(|dt| dt.to_string())
+    }
+
+    /// the local time and offset of a text value
+    fn local(s: &str) -> Option<(String, i32)> {
+        parse_local(s).map(|(dt, offset)| (dt.to_string(), offset))
+    }
+
+    #[test]
+    fn iso_style() {
+        assert_eq!(
+            text("2024-05-06 07:08:09.5"),
+            Some("2024-05-06 07:08:09.5".into())
+        );
+        assert_eq!(
+            local("2024-05-06 12:38:09.5+05:30"),
+            Some(("2024-05-06 12:38:09.5".into(), 19800))
+        );
+        assert_eq!(
+            text("2024-05-06 12:38:09.5+05:30"),
+            Some("2024-05-06 07:08:09.5".into())
+        );
+        assert_eq!(
+            text("2024-05-06 07:08:09-03"),
+            Some("2024-05-06 10:08:09".into())
+        );
+        assert_eq!(text("2024-05-26"), Some("2024-05-26 00:00:00".into()));
+        assert_eq!(text("0044-03-15 BC"), Some("0044-03-15 00:00:00 BC".into()));
+        assert_eq!(
+            text("0044-03-15 12:00:00 BC"),
+            Some("0044-03-15 12:00:00 BC".into())
+        );
+        assert_eq!(
+            text("0044-03-15 12:00:00+01 BC"),
+            Some("0044-03-15 11:00:00 BC".into())
+        );
+    }
+
+    #[test]
+    fn sql_style() {
+        assert_eq!(
+            text("05/06/2024 07:08:09.5"),
+            Some("2024-05-06 07:08:09.5".into())
+        );
+        assert_eq!(
+            text("05/06/2024 07:08:09.5 UTC"),
+            Some("2024-05-06 07:08:09.5".into())
+        );
+        assert_eq!(
+            local("05/06/2024 12:38:09.5 +0530"),
+            Some(("2024-05-06 12:38:09.5".into(), 19800))
+        );
+        assert_eq!(text("05/26/2024"), Some("2024-05-26 00:00:00".into()));
+        // DMY is read day first only when the day is above 12
+        assert_eq!(text("26/05/2024"), Some("2024-05-26 00:00:00".into()));
+        assert_eq!(
+            text("15/03/0044 12:00:00 BC"),
+            Some("0044-03-15 12:00:00 BC".into())
+        );
+        assert_eq!(text("03/15/0044 BC"), Some("0044-03-15 00:00:00 BC".into()));
+    }
+
+    #[test]
+    fn postgres_style() {
+        assert_eq!(
+            text("Mon May 06 07:08:09.5 2024"),
+            Some("2024-05-06 07:08:09.5".into())
+        );
+        assert_eq!(
+            text("Mon 06 May 07:08:09.5 2024 UTC"),
+            Some("2024-05-06 07:08:09.5".into())
+        );
+        assert_eq!(
+            local("Mon May 06 12:38:09.5 2024 +0530"),
+            Some(("2024-05-06 12:38:09.5".into(), 19800))
+        );
+        assert_eq!(
+            text("Fri Mar 15 12:00:00 0044 BC"),
+            Some("0044-03-15 12:00:00 BC".into())
+        );
+        // a DATE in the postgres style is numeric
+        assert_eq!(text("05-26-2024"), Some("2024-05-26 00:00:00".into()));
+        assert_eq!(text("15-03-0044 BC"), Some("0044-03-15 00:00:00 BC".into()));
+    }
+
+    #[test]
+    fn german_style() {
+        assert_eq!(
+            text("06.05.2024 07:08:09.5 UTC"),
+            Some("2024-05-06 07:08:09.5".into())
+        );
+        assert_eq!(
+            local("06.05.2024 12:38:09.5 +0530"),
+            Some(("2024-05-06 12:38:09.5".into(), 19800))
+        );
+        assert_eq!(text("26.05.2024"), Some("2024-05-26 00:00:00".into()));
+        assert_eq!(
+            text("15.03.0044 12:00:00 BC"),
+            Some("0044-03-15 12:00:00 BC".into())
+        );
+    }
+
+    #[test]
+    fn offset_with_seconds() {
+        // local mean time of amsterdam before 1937
+        assert_eq!(
+            local("1900-01-01 00:19:32+00:19:32"),
+            Some(("1900-01-01 00:19:32".into(), 1172))
+        );
+        assert_eq!(
+            text("1900-01-01 00:19:32+00:19:32"),
+            Some("1900-01-01 00:00:00".into())
+        );
+    }
+
+    #[test]
+    fn infinity() {
+        assert_eq!(parse_text("infinity"), Some(Datetime::INFINITY));
+        assert_eq!(parse_text("-infinity"), Some(Datetime::NEG_INFINITY));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(text("2024-05-262"), None);
+        assert_eq!(text("2024-02-30"), None);
+        assert_eq!(text("2024-05-06 07:08:09.5x"), None);
+        assert_eq!(text("2024-05"), None);
+        // an abbreviation other than UTC may name several zones
+        assert_eq!(text("05/06/2024 12:38:09.5 IST"), None);
+        assert_eq!(text("05/06/2024 12:38:09.5 +05301"), None);
+    }
+
+    // the expected bytes are those of timestamp_send, date_send, interval_send, timetz_send
+    // and range_send
+
+    #[test]
+    fn timestamp_bytes() {
+        let bytes = encode(dt("2000-01-01 00:00:01.5")).unwrap();
+        assert_eq!(hex(&bytes), "000000000016e360");
+        assert_eq!(
+            timestamp_from_micros(0x16e360).unwrap(),
+            dt("2000-01-01 00:00:01.5")
+        );
+        // rounded to microseconds as postgres does
+        let bytes = encode(dt("1999-12-31 23:59:59.9999995")).unwrap();
+        assert_eq!(hex(&bytes), "0000000000000000");
+    }
+
+    #[test]
+    fn timestamp_infinity() {
+        assert_eq!(
+            hex(&encode(Datetime::INFINITY).unwrap()),
+            "7fffffffffffffff"
+        );
+        assert_eq!(
+            hex(&encode(Datetime::NEG_INFINITY).unwrap()),
+            "8000000000000000"
+        );
+        assert_eq!(timestamp_from_micros(i64::MAX).unwrap(), Datetime::INFINITY);
+        assert_eq!(
+            timestamp_from_micros(i64::MIN).unwrap(),
+            Datetime::NEG_INFINITY
+        );
+    }
+
+    #[test]
+    fn timestamp_range() {
+        assert!(encode(dt("4713-01-01 00:00:00 BC")).is_ok());
+        assert!(encode(dt("4714-12-31 23:59:59 BC")).is_err());
+        assert!(encode(dt("294276-12-31 23:59:59.999999")).is_ok());
+        assert!(encode(dt("294277-01-01 00:00:00")).is_err());
+        assert!(timestamp_from_micros(i64::MAX - 1).is_err());
+    }
+
+    #[test]
+    fn date_days() {
+        assert_eq!(
+            hex(&encode(PgDate(dt("2024-05-06 07:08:09"))).unwrap()),
+            "000022bc"
+        );
+        assert_eq!(
+            hex(&encode(PgDate(dt("1999-12-31 00:00:00"))).unwrap()),
+            "ffffffff"
+        );
+        assert_eq!(
+            hex(&encode(PgDate(dt("4713-01-01 00:00:00 BC"))).unwrap()),
+            "ffda97cd"
+        );
+        assert_eq!(
+            hex(&encode(PgDate(Datetime::INFINITY)).unwrap()),
+            "7fffffff"
+        );
+        assert!(encode(PgDate(dt("4714-12-31 00:00:00 BC"))).is_err());
+        assert_eq!(date_from_days(0x22bc).unwrap(), dt("2024-05-06 00:00:00"));
+        assert_eq!(
+            date_from_days(-2451507).unwrap(),
+            dt("4713-01-01 00:00:00 BC")
+        );
+        assert_eq!(date_from_days(i32::MIN).unwrap(), Datetime::NEG_INFINITY);
+    }
+
+    #[test]
+    fn interval_bytes() {
+        let span = Span::from_str("1 year 2 mons -3 days 04:05:06.5").unwrap();
+        let bytes = encode(span).unwrap();
+        assert_eq!(hex(&bytes), "000000036c9361a0fffffffd0000000e");
+        assert_eq!(span_from_bytes(&bytes).unwrap(), span);
+        assert!(span_from_bytes(&bytes[..12]).is_err());
+    }
+
+    #[test]
+    fn interval_limits() {
+        for (s, bytes) in [
+            (
+                "-178956970 years -8 mons -2147483648 days -2562047788:00:54.775808",
+                "80000000000000008000000080000000",
+            ),
+            (
+                "178956970 years 7 mons 2147483647 days 2562047788:00:54.775807",
+                "7fffffffffffffff7fffffff7fffffff",
+            ),
+        ] {
+            let span = Span::from_str(s).unwrap();
+            assert_eq!(span.to_string(), s);
+            assert_eq!(hex(&encode(span).unwrap()), bytes);
+            assert_eq!(span_from_bytes(&hex_bytes(bytes)).unwrap(), span);
+        }
+    }
+
+    #[test]
+    fn timetz_bytes() {
+        // postgres keeps the zone in seconds west of utc
+        let time = OffsetTime::from_str("07:08:09.5+05:30").unwrap();
+        let bytes = encode(time).unwrap();
+        assert_eq!(hex(&bytes), "00000005fb36a960ffffb2a8");
+        assert_eq!(offset_time_from_bytes(&bytes).unwrap(), time);
+        let time = OffsetTime::from_str("24:00:00-03").unwrap();
+        assert_eq!(
+            offset_time_from_bytes(&encode(time).unwrap()).unwrap(),
+            time
+        );
+    }
+
+    #[test]
+    fn range_bytes() {
+        let range = DatetimeRange::new(
+            Bound::Included(dt("2000-01-01 00:00:00")),
+            Bound::Excluded(dt("2000-01-01 00:00:01.5")),
+        )
+        .unwrap();
+        let bytes = encode(range).unwrap();
+        assert_eq!(
+            hex(&bytes),
+            "0200000008000000000000000000000008000000000016e360"
+        );
+        assert_eq!(range_from_bytes(&bytes, "TIMESTAMP", |dt, _| dt), Ok(range));
+
+        let range =
+            DatetimeRange::new(Bound::Unbounded, Bound::Included(dt("2000-01-02 00:00:00")))
+                .unwrap();
+        let bytes = encode(range).unwrap();
+        assert_eq!(hex(&bytes), "0c00000008000000141dd76000");
+        assert_eq!(range_from_bytes(&bytes, "TIMESTAMP", |dt, _| dt), Ok(range));
+
+        let range: DatetimeRange = DatetimeRange::new(Bound::Unbounded, Bound::Unbounded).unwrap();
+        assert_eq!(hex(&encode(range).unwrap()), "18");
+        assert_eq!(
+            range_from_bytes(&[0x18], "TIMESTAMP", |dt, _| dt),
+            Ok(range)
+        );
+
+        assert_eq!(
+            hex(&encode(DatetimeRange::<Datetime>::EMPTY).unwrap()),
+            "01"
+        );
+        assert_eq!(
+            range_from_bytes(&[0x01], "TIMESTAMP", |dt, _| dt),
+            Ok(DatetimeRange::EMPTY)
+        );
+    }
+
+    #[test]
+    fn daterange_bytes() {
+        let range = DatetimeRange::new(
+            Bound::Included(PgDate(dt("2024-05-01 00:00:00"))),
+            Bound::Excluded(PgDate(dt("2024-05-08 00:00:00"))),
+        )
+        .unwrap();
+        let bytes = encode(range).unwrap();
+        assert_eq!(hex(&bytes), "0200000004000022b700000004000022be");
+        assert_eq!(
+            range_from_bytes(&bytes, "DATE", |dt, _| PgDate(dt)),
+            Ok(range)
+        );
+    }
+
+    #[test]
+    fn tstzrange_bytes() {
+        let start = OffsetDatetime::from_rfc3339("2000-01-01T02:00:00+02:00").unwrap();
+        let range = DatetimeRange::new(Bound::Included(start), Bound::Unbounded).unwrap();
+        let bytes = encode(range).unwrap();
+        assert_eq!(hex(&bytes), "12000000080000000000000000");
+        let read = range_from_bytes(&bytes, "TIMESTAMPTZ", OffsetDatetime::from_local).unwrap();
+        assert_eq!(
+            read.bounds(),
+            Some((Bound::Included(start.to_offset(0)), Bound::Unbounded))
+        );
+    }
+
+    #[test]
+    fn invalid_range_bytes() {
+        let point = |dt, _| dt;
+        assert!(range_from_bytes(&[], "TIMESTAMP", point).is_err());
+        // the length prefix promises more bytes than follow
+        assert!(range_from_bytes(&[0x0c, 0, 0, 0, 8, 0, 0], "TIMESTAMP", point).is_err());
+        // a DATE where a TIMESTAMP is expected
+        let bytes = hex_bytes("0200000004000022b700000004000022be");
+        assert!(range_from_bytes(&bytes, "TIMESTAMP", point).is_err());
+        // the start after the end
+        let bytes = hex_bytes("0200000004000022be00000004000022b7");
+        assert_eq!(
+            range_from_bytes(&bytes, "DATE", point),
+            Err("the start is after the end".into())
+        );
+    }
+
+    #[test]
+    fn range_text() {
+        let range = DatetimeRange::new(
+            Bound::Included(dt("2024-05-06 09:00:00")),
+            Bound::Excluded(dt("2024-05-06 17:00:00")),
+        )
+        .unwrap();
+        let text = range.to_string();
+        assert_eq!(text, "[\"2024-05-06 09:00:00\",\"2024-05-06 17:00:00\")");
+        assert_eq!(range_from_text(&text, |dt, _| dt), Ok(range));
+        assert_eq!(
+            range_from_text("empty", |dt, _| dt),
+            Ok(DatetimeRange::EMPTY)
+        );
+        assert_eq!(
+            range_from_text("(,\"infinity\"]", |dt, _| dt),
+            Ok(DatetimeRange::new(Bound::Unbounded, Bound::Included(Datetime::INFINITY)).unwrap())
+        );
+        // a tstzrange printed in a TimeZone of +02
+        let range = range_from_text("[\"2024-05-06 09:00:00+02\",)", |local, offset| {
+            OffsetDatetime::from_local(local, offset).utc()
+        });
+        assert_eq!(
+            range,
+            Ok(
+                DatetimeRange::new(Bound::Included(dt("2024-05-06 07:00:00")), Bound::Unbounded)
+                    .unwrap()
+            )
+        );
+        assert!(range_from_text("[2024-05-07,2024-05-06)", |dt, _| dt).is_err());
+        assert!(range_from_text("[2024-05-06", |dt, _| dt).is_err());
+        assert!(range_from_text("", |dt, _| dt).is_err());
+        assert!(range_from_text("[", |dt, _| dt).is_err());
+    }
+}
@@ -0,0 +1,143 @@
+use std::{fmt::Display, ops::Bound};
+
+use crate::Datetime;
+
+/// a range of Datetime or of another point in time such as an OffsetDatetime,
+/// each end included, excluded or unbounded, or the empty range. Like a postgres range the
+/// start may not come after the end, and a range with equal ends that excludes one is empty
+/// ```
+/// # use std::ops::Bound;
+/// # use sys_datetime::{Datetime, DatetimeRange};
+/// let start = Datetime::from_str("2024-05-06 09:00:00").unwrap();
+/// let end = Datetime::from_str("2024-05-06 17:00:00").unwrap();
+/// let open = DatetimeRange::new(Bound::Included(start), Bound::Excluded(end)).unwrap();
+/// assert_eq!(open.to_string(), "[\"2024-05-06 09:00:00\",\"2024-05-06 17:00:00\")");
+/// assert!(open.contains(&start));
+/// assert!(!open.contains(&end));
+///
+/// let after = DatetimeRange::new(Bound::Included(end), Bound::Unbounded).unwrap();
+/// assert!(!open.overlaps(&after));
+/// assert!(DatetimeRange::new(Bound::Unbounded, Bound::Unbounded).unwrap().contains_range(&open));
+///
+/// let empty = DatetimeRange::new(Bound::Included(end), Bound::Excluded(end)).unwrap();
+/// assert!(empty.is_empty());
+/// assert_eq!(empty.to_string(), "empty");
+/// assert_eq!(DatetimeRange::new(Bound::Included(end), Bound::Included(start)), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DatetimeRange<T = Datetime> {
+    bounds: Option<(Bound<T>, Bound<T>)>,
+}
+
+impl<T: PartialOrd + Copy> DatetimeRange<T> {
+    /// the range containing nothing
+    pub const EMPTY: Self = Self { bounds: None };
+
+    /// none when the start comes after the end
+    pub fn new(start: Bound<T>, end: Bound<T>) -> Option<Self> {
+        if let (Bound::Included(s) | Bound::Excluded(s), Bound::Included(e) | Bound::Excluded(e)) =
+            (start, end)
+        {
+            if s > e {
+                return None;
+            }
+            if s == e && !matches!((start, end), (Bound::Included(_), Bound::Included(_))) {
+                return Some(Self::EMPTY);
+            }
+        }
+        Some(Self {
+            bounds: Some((start, end)),
+        })
+    }
+
+    /// the start and end, none for the empty range
+    #[inline(always)]
+    pub fn bounds(&self) -> Option<(Bound<T>, Bound<T>)> {
+        self.bounds
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.bounds.is_none()
+    }
+
+    /// whether the point lies in the range
+    pub fn contains(&self, point: &T) -> bool {
+        self.bounds.is_some_and(|(start, end)| {
+            starts_before(start, Bound::Included(*point))
+                && ends_after(end, Bound::Included(*point))
+        })
+    }
+
+    /// whether every point of `other` lies in the range, the empty range lies in every range
+    pub fn contains_range(&self, other: &Self) -> bool {
+        match (self.bounds, other.bounds) {
+            (_, None) => true,
+            (None, _) => false,
+            (Some((start, end)), Some((other_start, other_end))) => {
+                starts_before(start, other_start) && ends_after(end, other_end)
+            }
+        }
+    }
+
+    /// whether the ranges share a point
+    pub fn overlaps(&self, other: &Self) -> bool {
+        match (self.bounds, other.bounds) {
+            (Some((start, end)), Some((other_start, other_end))) => {
+                reaches(start, other_end) && reaches(other_start, end)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// whether no point at or after the start `b` comes before the start `a`
+fn starts_before<T: PartialOrd>(a: Bound<T>, b: Bound<T>) -> bool {
+    match (a, b) {
+        (Bound::Unbounded, _) => true,
+        (_, Bound::Unbounded) => false,
+        (Bound::Included(a), Bound::Included(b) | Bound::Excluded(b))
+        | (Bound::Excluded(a), Bound::Excluded(b)) => a <= b,
+        (Bound::Excluded(a), Bound::Included(b)) => a < b,
+    }
+}
+
+/// whether no point at or before the end `b` comes after the end `a`
+fn ends_after<T: PartialOrd>(a: Bound<T>, b: Bound<T>) -> bool {
+    match (a, b) {
+        (Bound::Unbounded, _) => true,
+        (_, Bound::Unbounded) => false,
+        (Bound::Included(a), Bound::Included(b) | Bound::Excluded(b))
+        | (Bound::Excluded(a), Bound::Excluded(b)) => a >= b,
+        (Bound::Excluded(a), Bound::Included(b)) => a > b,
+    }
+}
+
+/// whether a range starting at `start` can reach a range ending at `end`
+fn reaches<T: PartialOrd>(start: Bound<T>, end: Bound<T>) -> bool {
+    match (start, end) {
+        (Bound::Included(s), Bound::Included(e)) => s <= e,
+        (Bound::Included(s) | Bound::Excluded(s), Bound::Included(e) | Bound::Excluded(e)) => s < e,
+        _ => true,
+    }
+}
+
+/// the text of a postgres range, "empty" or the ends in quotes such as
+/// "[\"2024-05-06 09:00:00\",)"
+impl<T: Display> Display for DatetimeRange<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((start, end)) = &self.bounds else {
+            return write!(f, "empty");
+        };
+        match start {
+            Bound::Included(s) => write!(f, "[\"{}\",", s)?,
+            Bound::Excluded(s) => write!(f, "(\"{}\",", s)?,
+            Bound::Unbounded => write!(f, "(,")?,
+        }
+        match end {
+            Bound::Included(e) => write!(f, "\"{}\"]", e),
+            Bound::Excluded(e) => write!(f, "\"{}\")", e),
+            Bound::Unbounded => write!(f, ")"),
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use std::{
+    io::{Error, ErrorKind, Result},
+    net::{ToSocketAddrs, UdpSocket},
+    time::Duration,
+};
+
+use crate::Datetime;
+
+/// leap indicator 0, version 4, mode 3 (client)
+const CLIENT_HEADER: u8 = 0b00_100_011;
+
+const PACKET_LEN: usize = 48;
+
+/// SNTP client as described by RFC 4330, it only reads the server clock and never sets the local one
+/// ```
+/// # use std::net::UdpSocket;
+/// # use sys_datetime::{Datetime, SntpClient};
+/// // a stand-in server whose clock is one hour ahead
+/// let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+/// let addr = server.local_addr().unwrap();
+/// std::thread::spawn(move || {
+///     let mut buf = [0u8; 48];
+///     let (_, peer) = server.recv_from(&mut buf).unwrap();
+///     let mut now = Datetime::now();
+///     now.add_hours(1);
+///     let mut reply = [0u8; 48];
+///     reply[0] = 0b00_100_100;
+///     reply[1] = 1;
+///     reply[24..32].copy_from_slice(&buf[40..48]);
+///     reply[32..40].copy_from_slice(&now.to_ntp().to_be_bytes());
+///     reply[40..48].copy_from_slice(&now.to_ntp().to_be_bytes());
+///     server.send_to(&reply, peer).unwrap();
+/// });
+///
+/// let reply = SntpClient::new(&addr.to_string()).query().unwrap();
+/// assert_eq!(reply.stratum, 1);
+/// assert!((reply.offset_nanos - 3_600_000_000_000).abs() < 2_000_000_000);
+/// assert!(reply.corrected_now() > Datetime::now());
+/// ```
+#[derive(Clone, Debug)]
+pub struct SntpClient {
+    server: String,
+    timeout: Duration,
+}
+
+/// the result of one SNTP exchange
+#[derive(Clone, Copy, Debug)]
+pub struct SntpReply {
+    /// stratum of the server, 1 is a primary reference
+    pub stratum: u8,
+    /// nanoseconds the local clock must be moved to agree with the server
+    pub offset_nanos: i64,
+    /// round-trip delay in nanoseconds, excluding the server processing time
+    pub delay_nanos: i64,
+    /// the corrected time at which the reply arrived
+    pub time: Datetime,
+}
+
+impl SntpClient {
+    /// client for a server such as "pool.ntp.org:123" or "192.168.1.1:123"
+    pub fn new(server: &str) -> Self {
+        Self {
+            server: server.to_string(),
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    /// set how long to wait for the reply, 5 seconds by default
+    pub fn set_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// send one request and compute the clock offset and round-trip delay
+    pub fn query(&self) -> Result<SntpReply> {
+        let server = self
+            .server
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| Error::new(ErrorKind::NotFound, "sntp server address not found"))?;
+        let socket = if server.is_ipv4() {
+            UdpSocket::bind("0.0.0.0:0")?
+        } else {
+            UdpSocket::bind("[::]:0")?
+        };
+        socket.set_read_timeout(Some(self.timeout))?;
+        socket.connect(server)?;
+
+        let mut request = [0u8; PACKET_LEN];
+        request[0] = CLIENT_HEADER;
+        let originate = Datetime::now();
+        let originate_ntp = originate.to_ntp();
+        request[40..48].copy_from_slice(&originate_ntp.to_be_bytes());
+        socket.send(&request)?;
+
+        let mut reply = [0u8; PACKET_LEN];
+        loop {
+            let len = socket.recv(&mut reply)?;
+            // ignore stray datagrams that do not answer this request
+            if len >= PACKET_LEN && reply[24..32] == originate_ntp.to_be_bytes() {
+                break;
+            }
+        }
+        let destination = Datetime::now();
+
+        let leap = reply[0] >> 6;
+        let mode = reply[0] & 0b111;
+        let stratum = reply[1];
+        if mode != 4 && mode != 5 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "sntp reply is not from a server",
+            ));
+        }
+        if leap == 3 || stratum == 0 || stratum > 15 {
+            return Err(Error::other("sntp server is not synchronized"));
+        }
+
+        let receive = timestamp_at(&reply, 32);
+        let transmit = timestamp_at(&reply, 40);
+        if transmit == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "sntp reply has no transmit time",
+            ));
+        }
+        let receive = Datetime::from_ntp_near(receive, destination);
+        let transmit = Datetime::from_ntp_near(transmit, destination);
+
+        let t1 = nanos(&originate);
+        let t2 = nanos(&receive);
+        let t3 = nanos(&transmit);
+        let t4 = nanos(&destination);
+        let delay_nanos = ((t4 - t1) - (t3 - t2)) as i64;
+        let offset_nanos = (((t2 - t1) + (t3 - t4)) / 2) as i64;
+
+        let mut time = destination;
+        time.add_nanoseconds(offset_nanos);
+
+        Ok(SntpReply {
+            stratum,
+            offset_nanos,
+            delay_nanos,
+            time,
+        })
+    }
+}
+
+impl SntpReply {
+    /// the local clock corrected by the measured offset
+    pub fn corrected_now(&self) -> Datetime {
+        let mut now = Datetime::now();
+        now.add_nanoseconds(self.offset_nanos);
+        now
+    }
+}
+
+fn timestamp_at(packet: &[u8], at: usize) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&packet[at..at + 8]);
+    u64::from_be_bytes(bytes)
+}
+
+/// nanoseconds since the ntp prime epoch
+fn nanos(dt: &Datetime) -> i128 {
+    dt.ntp_seconds() as i128 * 1_000_000_000 + dt.nanosecond as i128
+}
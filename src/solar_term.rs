@@ -0,0 +1,178 @@
+use std::fmt::Display;
+
+use crate::{
+    astro::{from_jde, normalize, sun_longitude, to_jde},
+    Datetime,
+};
+
+/// the 24 solar terms (节气), each starts when the apparent longitude of the sun
+/// reaches a multiple of 15 degrees
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SolarTerm {
+    /// 小寒, 285°
+    MinorCold,
+    /// 大寒, 300°
+    MajorCold,
+    /// 立春, 315°
+    StartOfSpring,
+    /// 雨水, 330°
+    RainWater,
+    /// 惊蛰, 345°
+    AwakeningOfInsects,
+    /// 春分, 0°
+    SpringEquinox,
+    /// 清明, 15°
+    PureBrightness,
+    /// 谷雨, 30°
+    GrainRain,
+    /// 立夏, 45°
+    StartOfSummer,
+    /// 小满, 60°
+    GrainBuds,
+    /// 芒种, 75°
+    GrainInEar,
+    /// 夏至, 90°
+    SummerSolstice,
+    /// 小暑, 105°
+    MinorHeat,
+    /// 大暑, 120°
+    MajorHeat,
+    /// 立秋, 135°
+    StartOfAutumn,
+    /// 处暑, 150°
+    EndOfHeat,
+    /// 白露, 165°
+    WhiteDew,
+    /// 秋分, 180°
+    AutumnEquinox,
+    /// 寒露, 195°
+    ColdDew,
+    /// 霜降, 210°
+    FrostsDescent,
+    /// 立冬, 225°
+    StartOfWinter,
+    /// 小雪, 240°
+    MinorSnow,
+    /// 大雪, 255°
+    MajorSnow,
+    /// 冬至, 270°
+    WinterSolstice,
+}
+
+const NAMES: [&str; 24] = [
+    "小寒", "大寒", "立春", "雨水", "惊蛰", "春分", "清明", "谷雨", "立夏", "小满", "芒种", "夏至",
+    "小暑", "大暑", "立秋", "处暑", "白露", "秋分", "寒露", "霜降", "立冬", "小雪", "大雪", "冬至",
+];
+
+impl SolarTerm {
+    /// all terms in the order they occur in a gregorian year
+    pub const ALL: [SolarTerm; 24] = [
+        SolarTerm::MinorCold,
+        SolarTerm::MajorCold,
+        SolarTerm::StartOfSpring,
+        SolarTerm::RainWater,
+        SolarTerm::AwakeningOfInsects,
+        SolarTerm::SpringEquinox,
+        SolarTerm::PureBrightness,
+        SolarTerm::GrainRain,
+        SolarTerm::StartOfSummer,
+        SolarTerm::GrainBuds,
+        SolarTerm::GrainInEar,
+        SolarTerm::SummerSolstice,
+        SolarTerm::MinorHeat,
+        SolarTerm::MajorHeat,
+        SolarTerm::StartOfAutumn,
+        SolarTerm::EndOfHeat,
+        SolarTerm::WhiteDew,
+        SolarTerm::AutumnEquinox,
+        SolarTerm::ColdDew,
+        SolarTerm::FrostsDescent,
+        SolarTerm::StartOfWinter,
+        SolarTerm::MinorSnow,
+        SolarTerm::MajorSnow,
+        SolarTerm::WinterSolstice,
+    ];
+
+    /// the chinese name such as "立春"
+    pub fn name(&self) -> &'static str {
+        NAMES[*self as usize]
+    }
+
+    /// the apparent solar longitude in degrees at which the term starts
+    pub fn longitude(&self) -> f64 {
+        normalize(285.0 + 15.0 * *self as usize as f64)
+    }
+
+    /// the UTC instant the term starts in the given gregorian year, rounded to the second.
+    /// The sun is placed by a truncated VSOP87 theory good to about an arcsecond, so for
+    /// 1800-2100 the result is within a minute or two, mostly from the estimate of TT - UT
+    /// ```
+    /// # use sys_datetime::{Datetime, SolarTerm};
+    /// let dt = SolarTerm::StartOfSpring.instant(2024);
+    /// let expected = Datetime::from_rfc3339("2024-02-04 08:27:00").unwrap();
+    /// assert!(dt.seconds_since(expected).abs() <= 60);
+    ///
+    /// let dt = SolarTerm::WinterSolstice.instant(2024);
+    /// let expected = Datetime::from_rfc3339("2024-12-21 09:20:00").unwrap();
+    /// assert!(dt.seconds_since(expected).abs() <= 60);
+    /// ```
+    pub fn instant(&self, year: i64) -> Datetime {
+        let target = self.longitude();
+        // degrees past the spring equinox, the terms from 小寒 to 惊蛰 come before it
+        let degrees = normalize(target - 285.0) - 75.0;
+        let mut guess = Datetime::from_ymd(year, 3, 20);
+        guess.add_hours(12);
+        let mut jde = to_jde(&guess) + degrees * 365.2422 / 360.0;
+        for _ in 0..20 {
+            let delta = normalize(target - sun_longitude(jde) + 180.0) - 180.0;
+            jde += delta * 365.2422 / 360.0;
+            if delta.abs() < 1e-7 {
+                break;
+            }
+        }
+        from_jde(jde)
+    }
+}
+
+impl Display for SolarTerm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Datetime {
+    /// the UTC instants of all 24 solar terms in a gregorian year, in order
+    /// ```
+    /// # use sys_datetime::{Datetime, SolarTerm};
+    /// let terms = Datetime::solar_terms(2024);
+    /// assert_eq!(terms[5].0, SolarTerm::SpringEquinox);
+    /// assert_eq!(terms[5].1.day(), 20);
+    /// ```
+    pub fn solar_terms(year: i64) -> [(SolarTerm, Datetime); 24] {
+        SolarTerm::ALL.map(|term| (term, term.instant(year)))
+    }
+
+    /// the apparent geocentric longitude of the sun in degrees at this UTC instant,
+    /// NaN for INFINITY and NEG_INFINITY
+    pub fn solar_longitude(&self) -> f64 {
+        sun_longitude(to_jde(self))
+    }
+
+    /// the solar term this UTC instant falls in, the latest one that has started,
+    /// none for INFINITY and NEG_INFINITY
+    /// ```
+    /// # use sys_datetime::{Datetime, SolarTerm};
+    /// let dt = Datetime::from_rfc3339("2024-04-04 12:00:00").unwrap();
+    /// assert_eq!(dt.solar_term(), Some(SolarTerm::PureBrightness));
+    /// assert_eq!(dt.solar_term().unwrap().name(), "清明");
+    /// assert!(Datetime::INFINITY.solar_longitude().is_nan());
+    /// assert_eq!(Datetime::INFINITY.solar_term(), None);
+    /// ```
+    pub fn solar_term(&self) -> Option<SolarTerm> {
+        if self.is_infinite() {
+            return None;
+        }
+        let index = (normalize(self.solar_longitude() - 285.0) / 15.0) as usize;
+        Some(SolarTerm::ALL[index.min(23)])
+    }
+}
@@ -0,0 +1,173 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::{parse_fraction, Datetime};
+
+/// a calendar duration of months, days and microseconds kept apart like a postgres INTERVAL,
+/// a month is not a fixed number of days and a day is not always 24 hours across a zone change.
+/// Display and from_str use the default postgres IntervalStyle
+/// ```
+/// # use sys_datetime::{Datetime, Span};
+/// let span = Span::from_str("1 year 2 mons 3 days 04:05:06.5").unwrap();
+/// assert_eq!((span.months(), span.days(), span.microseconds()), (14, 3, 14_706_500_000));
+/// assert_eq!(span.to_string(), "1 year 2 mons 3 days 04:05:06.5");
+/// assert_eq!(Span::new(0, -1, 7_200_000_000).to_string(), "-1 days +02:00:00");
+///
+/// let mut dt = Datetime::from_str("2024-01-31 00:00:00").unwrap();
+/// dt.add_span(&Span::new(1, 1, 3_600_000_000));
+/// assert_eq!(dt.to_string(), "2024-03-01 01:00:00");
+///
+/// // the largest postgres INTERVAL of hours, '2562047788:00:54.775807'
+/// let mut dt = Datetime::from_str("1970-01-01 00:00:00").unwrap();
+/// dt.add_span(&Span::new(0, 0, i64::MAX));
+/// assert_eq!(dt.to_string(), "294247-01-10 04:00:54.775807");
+/// let mut dt = Datetime::from_str("290000-01-01 00:00:00").unwrap();
+/// dt.add_span(&Span::new(0, 0, -i64::MAX));
+/// assert_eq!(dt.to_string(), "2279-12-22 19:59:05.224193 BC");
+/// ```
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct Span {
+    months: i32,
+    days: i32,
+    microseconds: i64,
+}
+
+impl Span {
+    pub fn new(months: i32, days: i32, microseconds: i64) -> Self {
+        Self {
+            months,
+            days,
+            microseconds,
+        }
+    }
+
+    #[inline(always)]
+    pub fn months(&self) -> i64 {
+        self.months as i64
+    }
+
+    #[inline(always)]
+    pub fn days(&self) -> i64 {
+        self.days as i64
+    }
+
+    #[inline(always)]
+    pub fn microseconds(&self) -> i64 {
+        self.microseconds
+    }
+
+    /// create from the postgres IntervalStyle such as "1 year 2 mons", "-1 days +02:03:00"
+    /// or "100:00:00.25", none if a field overflows
+    /// ```
+    /// # use sys_datetime::Span;
+    /// for s in ["-2562047788:00:54.775808", "2562047788:00:54.775807"] {
+    ///     assert_eq!(Span::from_str(s).unwrap().to_string(), s);
+    /// }
+    /// assert_eq!(Span::from_str("-2562047788:00:54.775808").unwrap().microseconds(), i64::MIN);
+    /// assert_eq!(Span::from_str("-2562047788:00:54.775809"), None);
+    /// assert_eq!(Span::from_str("2562047788:00:54.775808"), None);
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        let mut span = Self::default();
+        let mut tokens = s.split_whitespace();
+        while let Some(token) = tokens.next() {
+            if token.contains(':') {
+                let (sign, time) = match token.strip_prefix('-') {
+                    Some(time) => (-1, time),
+                    None => (1, token.strip_prefix('+').unwrap_or(token)),
+                };
+                let mut hms = time.split(':');
+                let hours: i64 = hms.next()?.parse().ok()?;
+                let minutes: i64 = hms.next()?.parse().ok()?;
+                let second = hms.next().unwrap_or("0");
+                let (second, fraction) = second.split_once('.').unwrap_or((second, ""));
+                let seconds: i64 = second.parse().ok()?;
+                if hms.next().is_some() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+                    return None;
+                }
+                let micros = [
+                    (hours, 3_600_000_000),
+                    (minutes, 60_000_000),
+                    (seconds, 1_000_000),
+                ]
+                .into_iter()
+                .try_fold(
+                    sign * (parse_fraction(fraction) as i64 + 500) / 1000,
+                    |n, (v, unit)| n.checked_add((sign * v).checked_mul(unit)?),
+                )?;
+                span.microseconds = span.microseconds.checked_add(micros)?;
+            } else {
+                let n: i32 = token.parse().ok()?;
+                match tokens.next()? {
+                    "year" | "years" => {
+                        span.months = span.months.checked_add(n.checked_mul(12)?)?
+                    }
+                    "mon" | "mons" => span.months = span.months.checked_add(n)?,
+                    "day" | "days" => span.days = span.days.checked_add(n)?,
+                    _ => return None,
+                }
+            }
+        }
+        Some(span)
+    }
+}
+
+impl From<Duration> for Span {
+    /// microseconds of the duration, the fraction below is dropped
+    fn from(duration: Duration) -> Self {
+        Self::new(0, 0, duration.as_micros() as i64)
+    }
+}
+
+impl Datetime {
+    /// plus the months, then the days, then the microseconds of a span like postgres does
+    pub fn add_span(&mut self, span: &Span) -> &mut Self {
+        if !self.is_infinite() {
+            self.add_months(span.months as i64)
+                .add_days(span.days as i64)
+                .add_seconds(span.microseconds.div_euclid(1_000_000))
+                .add_nanoseconds(span.microseconds.rem_euclid(1_000_000) * 1000);
+        }
+        self
+    }
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut negative = false;
+        let mut fields = Vec::new();
+        let (years, months) = (self.months / 12, self.months % 12);
+        for (n, unit) in [(years, "year"), (months, "mon"), (self.days, "day")] {
+            if n != 0 {
+                let sign = if negative && n > 0 { "+" } else { "" };
+                let plural = if n == 1 { "" } else { "s" };
+                fields.push(format!("{}{} {}{}", sign, n, unit, plural));
+                negative = n < 0;
+            }
+        }
+        if self.microseconds != 0 || fields.is_empty() {
+            let sign = if self.microseconds < 0 {
+                "-"
+            } else if negative {
+                "+"
+            } else {
+                ""
+            };
+            let micros = self.microseconds.unsigned_abs();
+            let seconds = micros / 1_000_000;
+            let mut time = format!(
+                "{}{:0>2}:{:0>2}:{:0>2}",
+                sign,
+                seconds / 3600,
+                seconds / 60 % 60,
+                seconds % 60
+            );
+            if !micros.is_multiple_of(1_000_000) {
+                let fraction = format!("{:0>6}", micros % 1_000_000);
+                time = format!("{}.{}", time, fraction.trim_end_matches('0'));
+            }
+            fields.push(time);
+        }
+        write!(f, "{}", fields.join(" "))
+    }
+}
@@ -0,0 +1,156 @@
+use sqlx::{
+    encode::IsNull,
+    error::BoxDynError,
+    sqlite::{SqliteArgumentsBuffer, SqliteTypeInfo, SqliteValueRef},
+    Decode, Encode, Sqlite, Type, ValueRef,
+};
+
+use crate::Datetime;
+
+impl Type<Sqlite> for Datetime {
+    fn type_info() -> SqliteTypeInfo {
+        <&str as Type<Sqlite>>::type_info()
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        matches!(
+            ty.to_string().as_str(),
+            "TEXT" | "REAL" | "INTEGER" | "NUMERIC" | "DATETIME" | "DATE"
+        )
+    }
+}
+
+/// TEXT in the format of Display such as "2024-05-06 07:08:09.5", sqlite has no date type and
+/// its date and time functions also accept the REAL of [`SqliteJulianDay`] and the INTEGER of
+/// [`SqliteUnix`]
+/// ```
+/// # use sqlx::{Connection, Row};
+/// # use sys_datetime::{Datetime, SqliteJulianDay, SqliteUnix};
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> sqlx::Result<()> {
+/// let mut conn = sqlx::SqliteConnection::connect("sqlite::memory:").await?;
+/// sqlx::query("CREATE TABLE event (at DATETIME)").execute(&mut conn).await?;
+/// let dt = Datetime::from_str("2024-05-06 07:08:09.5").unwrap();
+/// sqlx::query("INSERT INTO event VALUES ($1), ($2), ($3)")
+///     .bind(dt)
+///     .bind(SqliteJulianDay(dt))
+///     .bind(SqliteUnix(dt))
+///     .execute(&mut conn)
+///     .await?;
+/// let rows = sqlx::query("SELECT at, typeof(at), datetime(at, 'auto', 'subsec') FROM event")
+///     .fetch_all(&mut conn)
+///     .await?;
+/// let read: Vec<(Datetime, String, String)> =
+///     rows.iter().map(|row| (row.get(0), row.get(1), row.get(2))).collect();
+/// assert_eq!((read[0].0, read[0].1.as_str()), (dt, "text"));
+/// assert_eq!((read[1].0, read[1].1.as_str()), (dt, "real"));
+/// assert_eq!(read[2].0.to_string(), "2024-05-06 07:08:09");
+/// assert_eq!(read[2].1, "integer");
+/// assert!(read.iter().all(|(_, _, text)| text.starts_with("2024-05-06 07:08:09")));
+///
+/// // the dates sqlite makes itself
+/// let row = sqlx::query(
+///     "SELECT '2024-05-06T07:08:09+02:00', julianday('2024-05-06 07:08:09.5'), \
+///      unixepoch('2024-05-06 07:08:09')",
+/// )
+/// .fetch_one(&mut conn)
+/// .await?;
+/// let (text, real, integer): (Datetime, Datetime, Datetime) = (row.get(0), row.get(1), row.get(2));
+/// assert_eq!(text.to_string(), "2024-05-06 05:08:09");
+/// assert_eq!(real, dt);
+/// assert_eq!(integer.to_string(), "2024-05-06 07:08:09");
+/// # Ok(())
+/// # }
+/// ```
+impl<'q> Encode<'q, Sqlite> for Datetime {
+    fn encode_by_ref(&self, buf: &mut SqliteArgumentsBuffer) -> Result<IsNull, BoxDynError> {
+        Encode::<Sqlite>::encode(self.to_string(), buf)
+    }
+}
+
+/// TEXT in the format of Display or rfc3339 with the offset moved to utc, REAL as a julian
+/// day rounded to milliseconds and INTEGER as unix seconds
+impl<'r> Decode<'r, Sqlite> for Datetime {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        match value.type_info().to_string().as_str() {
+            "REAL" => {
+                let mut dt = Datetime::from_julian_day(<f64 as Decode<Sqlite>>::decode(value)?);
+                dt.round_subsec(3);
+                Ok(dt)
+            }
+            "INTEGER" => Ok(Datetime::from_unix_seconds(
+                <i64 as Decode<Sqlite>>::decode(value)?,
+            )),
+            _ => {
+                let s = <&str as Decode<Sqlite>>::decode(value)?;
+                let dt = if s.ends_with(" BC") {
+                    Datetime::from_str(s)
+                } else {
+                    Datetime::from_rfc3339(s).or_else(|| Datetime::from_str(s))
+                };
+                dt.ok_or_else(|| format!("{:?} is not a sqlite date", s).into())
+            }
+        }
+    }
+}
+
+/// a Datetime stored as a REAL julian day, about a millisecond of precision.
+/// Infinite values are stored as TEXT, all storages are read like a Datetime
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct SqliteJulianDay(pub Datetime);
+
+impl Type<Sqlite> for SqliteJulianDay {
+    fn type_info() -> SqliteTypeInfo {
+        <f64 as Type<Sqlite>>::type_info()
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        <Datetime as Type<Sqlite>>::compatible(ty)
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for SqliteJulianDay {
+    fn encode_by_ref(&self, buf: &mut SqliteArgumentsBuffer) -> Result<IsNull, BoxDynError> {
+        if self.0.is_infinite() {
+            return Encode::<Sqlite>::encode_by_ref(&self.0, buf);
+        }
+        Encode::<Sqlite>::encode(self.0.to_julian_day(), buf)
+    }
+}
+
+impl<'r> Decode<'r, Sqlite> for SqliteJulianDay {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        <Datetime as Decode<Sqlite>>::decode(value).map(SqliteJulianDay)
+    }
+}
+
+/// a Datetime stored as INTEGER seconds since 1970-01-01, the fraction of a second is dropped.
+/// The date functions of sqlite read it with the 'unixepoch' or 'auto' modifier.
+/// Infinite values are stored as TEXT, all storages are read like a Datetime
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct SqliteUnix(pub Datetime);
+
+impl Type<Sqlite> for SqliteUnix {
+    fn type_info() -> SqliteTypeInfo {
+        <i64 as Type<Sqlite>>::type_info()
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        <Datetime as Type<Sqlite>>::compatible(ty)
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for SqliteUnix {
+    fn encode_by_ref(&self, buf: &mut SqliteArgumentsBuffer) -> Result<IsNull, BoxDynError> {
+        if self.0.is_infinite() {
+            return Encode::<Sqlite>::encode_by_ref(&self.0, buf);
+        }
+        Encode::<Sqlite>::encode(self.0.unix_seconds(), buf)
+    }
+}
+
+impl<'r> Decode<'r, Sqlite> for SqliteUnix {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        <Datetime as Decode<Sqlite>>::decode(value).map(SqliteUnix)
+    }
+}
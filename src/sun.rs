@@ -0,0 +1,183 @@
+use crate::{
+    astro::{ephemeris_day, sidereal_time, sun_equatorial},
+    Datetime,
+};
+
+/// sidereal degrees per solar day
+const SIDEREAL_RATE: f64 = 360.98564736629;
+
+/// the altitude of the centre of the sun that starts and ends the day
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Twilight {
+    /// sunrise and sunset, the upper limb on the horizon with standard refraction, -0.833°
+    Official,
+    /// civil dawn and dusk, -6°
+    Civil,
+    /// nautical dawn and dusk, -12°
+    Nautical,
+    /// astronomical dawn and dusk, -18°
+    Astronomical,
+}
+
+impl Twilight {
+    /// the altitude of the centre of the sun in degrees
+    pub fn altitude(&self) -> f64 {
+        match self {
+            Twilight::Official => -0.833,
+            Twilight::Civil => -6.0,
+            Twilight::Nautical => -12.0,
+            Twilight::Astronomical => -18.0,
+        }
+    }
+}
+
+/// how the sun crosses an altitude during a day
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SunEvents {
+    /// the sun rises and sets, both in utc
+    RiseSet(Datetime, Datetime),
+    /// the sun rises and stays above the altitude to the end of the day, in utc. Near the polar
+    /// circles on the first day of a polar day
+    RiseOnly(Datetime),
+    /// the sun sets having stayed above the altitude since the day began, in utc. Near the polar
+    /// circles on the last day of a polar day
+    SetOnly(Datetime),
+    /// the sun stays above the altitude all day, polar day or a white night
+    AlwaysAbove,
+    /// the sun stays below the altitude all day, polar night
+    AlwaysBelow,
+}
+
+impl Datetime {
+    /// utc instant of the transit of the sun at a longitude in degrees, east positive.
+    /// The date of self is the local date at the location, the time of day is ignored,
+    /// none for INFINITY and NEG_INFINITY
+    /// ```
+    /// # use sys_datetime::Datetime;
+    /// let dt = Datetime::from_str("2024-06-20 00:00:00").unwrap();
+    /// assert_eq!(dt.solar_noon(-0.1276).unwrap().to_string(), "2024-06-20 12:02:13");
+    /// assert_eq!(Datetime::INFINITY.solar_noon(-0.1276), None);
+    /// ```
+    pub fn solar_noon(&self, longitude: f64) -> Option<Self> {
+        if self.is_infinite() {
+            return None;
+        }
+        let jd = self.julian_day_number() as f64 - longitude / 360.0;
+        Some(instant(transit(jd, longitude)))
+    }
+
+    /// utc instants the sun rises and sets at a location in degrees, north and east positive,
+    /// or dawn and dusk for a twilight below the horizon.
+    /// The date of self is the local date at the location, the time of day is ignored,
+    /// none for INFINITY and NEG_INFINITY
+    /// ```
+    /// # use sys_datetime::{Datetime, SunEvents, Twilight};
+    /// let dt = Datetime::from_str("2024-06-20 00:00:00").unwrap();
+    /// let Some(SunEvents::RiseSet(rise, set)) =
+    ///     dt.sunrise_sunset(51.5072, -0.1276, Twilight::Official)
+    /// else {
+    ///     panic!()
+    /// };
+    /// assert_eq!(rise.to_string(), "2024-06-20 03:42:59");
+    /// assert_eq!(set.to_string(), "2024-06-20 20:21:28");
+    ///
+    /// // tromsø has a polar day in june and a polar night in december
+    /// assert_eq!(
+    ///     dt.sunrise_sunset(69.65, 18.96, Twilight::Official),
+    ///     Some(SunEvents::AlwaysAbove)
+    /// );
+    /// // the sun rises once on the first day of the polar day and sets once on the last
+    /// let first = Datetime::from_str("2024-05-17 00:00:00").unwrap();
+    /// let Some(SunEvents::RiseOnly(rise)) = first.sunrise_sunset(69.65, 18.96, Twilight::Official)
+    /// else {
+    ///     panic!()
+    /// };
+    /// assert_eq!(rise.to_string(), "2024-05-16 23:07:48");
+    /// let last = Datetime::from_str("2024-07-25 00:00:00").unwrap();
+    /// let Some(SunEvents::SetOnly(set)) = last.sunrise_sunset(69.65, 18.96, Twilight::Official)
+    /// else {
+    ///     panic!()
+    /// };
+    /// assert_eq!(set.to_string(), "2024-07-25 22:23:24");
+    /// let dt = Datetime::from_str("2024-12-20 00:00:00").unwrap();
+    /// assert_eq!(
+    ///     dt.sunrise_sunset(69.65, 18.96, Twilight::Official),
+    ///     Some(SunEvents::AlwaysBelow)
+    /// );
+    /// assert!(matches!(
+    ///     dt.sunrise_sunset(69.65, 18.96, Twilight::Civil),
+    ///     Some(SunEvents::RiseSet(..))
+    /// ));
+    /// assert_eq!(Datetime::NEG_INFINITY.sunrise_sunset(0.0, 0.0, Twilight::Official), None);
+    /// ```
+    pub fn sunrise_sunset(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        twilight: Twilight,
+    ) -> Option<SunEvents> {
+        if self.is_infinite() {
+            return None;
+        }
+        let noon = transit(
+            self.julian_day_number() as f64 - longitude / 360.0,
+            longitude,
+        );
+        let rise = crossing(noon, latitude, longitude, twilight.altitude(), -1.0);
+        let set = crossing(noon, latitude, longitude, twilight.altitude(), 1.0);
+        Some(match (rise, set) {
+            (Ok(rise), Ok(set)) => SunEvents::RiseSet(instant(rise), instant(set)),
+            (Ok(rise), Err(_)) => SunEvents::RiseOnly(instant(rise)),
+            (Err(_), Ok(set)) => SunEvents::SetOnly(instant(set)),
+            (Err(true), Err(_)) | (Err(_), Err(true)) => SunEvents::AlwaysAbove,
+            (Err(false), Err(false)) => SunEvents::AlwaysBelow,
+        })
+    }
+}
+
+/// julian day of the transit nearest to `jd`
+fn transit(mut jd: f64, longitude: f64) -> f64 {
+    for _ in 0..3 {
+        let (ra, _) = sun_equatorial(ephemeris_day(jd));
+        jd -= hour_angle(jd, longitude, ra) / SIDEREAL_RATE;
+    }
+    jd
+}
+
+/// julian day the sun passes `altitude` before (`side` -1) or after (`side` 1) the transit `noon`,
+/// or whether it stays above the altitude
+fn crossing(
+    noon: f64,
+    latitude: f64,
+    longitude: f64,
+    altitude: f64,
+    side: f64,
+) -> Result<f64, bool> {
+    let mut jd = noon;
+    for _ in 0..4 {
+        let (ra, dec) = sun_equatorial(ephemeris_day(jd));
+        let (lat, dec) = (latitude.to_radians(), dec.to_radians());
+        let cos_h0 =
+            (altitude.to_radians().sin() - lat.sin() * dec.sin()) / (lat.cos() * dec.cos());
+        if cos_h0 < -1.0 {
+            return Err(true);
+        }
+        if cos_h0 > 1.0 {
+            return Err(false);
+        }
+        let target = side * cos_h0.acos().to_degrees();
+        jd += (target - hour_angle(jd, longitude, ra)) / SIDEREAL_RATE;
+    }
+    Ok(jd)
+}
+
+/// local hour angle of the sun in degrees between -180 and 180
+fn hour_angle(jd: f64, longitude: f64, ra: f64) -> f64 {
+    (sidereal_time(jd) + longitude - ra + 180.0).rem_euclid(360.0) - 180.0
+}
+
+fn instant(jd: f64) -> Datetime {
+    let mut dt = Datetime::from_julian_day(jd);
+    dt.round_subsec(0);
+    dt
+}
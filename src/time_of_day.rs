@@ -0,0 +1,177 @@
+use std::fmt::Display;
+
+use crate::{
+    offset::{format_offset, parse_offset},
+    parse_fraction, Datetime,
+};
+
+/// a time of day without a date, from 00:00:00 to 24:00:00 like a postgres TIME
+/// ```
+/// # use sys_datetime::{Datetime, TimeOfDay};
+/// let time = TimeOfDay::from_str("07:08:09.5").unwrap();
+/// assert_eq!((time.hour(), time.minute(), time.second()), (7, 8, 9));
+/// assert_eq!(time.to_string(), "07:08:09.5");
+/// assert_eq!(TimeOfDay::from_str("24:00:00"), TimeOfDay::new(24, 0, 0, 0));
+/// assert_eq!(TimeOfDay::from_str("24:00:01"), None);
+///
+/// let dt = Datetime::from_str("2024-05-06 07:08:09.5").unwrap();
+/// assert_eq!(dt.time_of_day(), time);
+/// ```
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TimeOfDay {
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+}
+
+impl TimeOfDay {
+    /// none unless the time lies between 00:00:00 and 24:00:00
+    pub fn new(hour: u8, minute: u8, second: u8, nanosecond: u32) -> Option<Self> {
+        let time = Self {
+            hour,
+            minute,
+            second,
+            nanosecond,
+        };
+        if minute > 59 || second > 59 || nanosecond >= 1_000_000_000 {
+            return None;
+        }
+        if hour > 24 || hour == 24 && time.nanoseconds() != 24 * 3_600_000_000_000 {
+            return None;
+        }
+        Some(time)
+    }
+
+    #[inline(always)]
+    pub fn hour(&self) -> i64 {
+        self.hour as i64
+    }
+
+    #[inline(always)]
+    pub fn minute(&self) -> i64 {
+        self.minute as i64
+    }
+
+    #[inline(always)]
+    pub fn second(&self) -> i64 {
+        self.second as i64
+    }
+
+    #[inline(always)]
+    pub fn nanosecond(&self) -> i64 {
+        self.nanosecond as i64
+    }
+
+    /// nanoseconds since midnight
+    pub fn nanoseconds(&self) -> i64 {
+        (self.hour as i64 * 3600 + self.minute as i64 * 60 + self.second as i64) * 1_000_000_000
+            + self.nanosecond as i64
+    }
+
+    /// the time `nanoseconds` after midnight, none past 24:00:00
+    pub fn from_nanoseconds(nanoseconds: i64) -> Option<Self> {
+        if !(0..=24 * 3_600_000_000_000).contains(&nanoseconds) {
+            return None;
+        }
+        let seconds = nanoseconds / 1_000_000_000;
+        Self::new(
+            (seconds / 3600) as u8,
+            (seconds / 60 % 60) as u8,
+            (seconds % 60) as u8,
+            (nanoseconds % 1_000_000_000) as u32,
+        )
+    }
+
+    /// create from "hh:mm", "hh:mm:ss" or "hh:mm:ss.fraction"
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        let mut hms = s.trim().split(':');
+        let hour = hms.next()?.parse().ok()?;
+        let minute = hms.next()?.parse().ok()?;
+        let second = hms.next().unwrap_or("0");
+        let (second, fraction) = second.split_once('.').unwrap_or((second, ""));
+        if hms.next().is_some() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        Self::new(hour, minute, second.parse().ok()?, parse_fraction(fraction))
+    }
+}
+
+impl Datetime {
+    /// the time of day of self
+    pub fn time_of_day(&self) -> TimeOfDay {
+        TimeOfDay {
+            hour: self.hour,
+            minute: self.minute,
+            second: self.second,
+            nanosecond: self.nanosecond,
+        }
+    }
+}
+
+impl Display for TimeOfDay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:0>2}:{:0>2}:{:0>2}",
+            self.hour, self.minute, self.second
+        )?;
+        if self.nanosecond != 0 {
+            let fraction = format!("{:0>9}", self.nanosecond);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+        Ok(())
+    }
+}
+
+/// a time of day with the utc offset it is kept at, a postgres TIMETZ
+/// ```
+/// # use sys_datetime::{OffsetTime, TimeOfDay};
+/// let time = OffsetTime::from_str("07:08:09+05:30").unwrap();
+/// assert_eq!(time.time(), TimeOfDay::new(7, 8, 9, 0).unwrap());
+/// assert_eq!(time.offset(), 19800);
+/// assert_eq!(time.to_string(), "07:08:09+05:30");
+/// assert_eq!(OffsetTime::from_str("07:08:09-03").unwrap().offset(), -10800);
+/// ```
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct OffsetTime {
+    time: TimeOfDay,
+    offset: i32,
+}
+
+impl OffsetTime {
+    /// the time of day `time` at `offset` seconds east of utc
+    pub fn new(time: TimeOfDay, offset: i32) -> Self {
+        Self { time, offset }
+    }
+
+    #[inline(always)]
+    pub fn time(&self) -> TimeOfDay {
+        self.time
+    }
+
+    /// seconds east of utc
+    #[inline(always)]
+    pub fn offset(&self) -> i32 {
+        self.offset
+    }
+
+    /// create from a time of day followed by an offset such as "07:08:09.5+02",
+    /// "07:08-05:30" or "07:08:09Z", without an offset the time is utc
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let (time, offset) = match s.find(['+', '-', 'Z']) {
+            Some(p) => (&s[..p], parse_offset(&s[p..])?),
+            None => (s, 0),
+        };
+        Some(Self::new(TimeOfDay::from_str(time)?, offset))
+    }
+}
+
+impl Display for OffsetTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.time, format_offset(self.offset))
+    }
+}